@@ -67,6 +67,7 @@ pub fn print_ranking() {
         ("GuildChannel", std::mem::size_of::<GuildChannel>()),
         ("GuildCreateEvent", std::mem::size_of::<GuildCreateEvent>()),
         ("GuildDeleteEvent", std::mem::size_of::<GuildDeleteEvent>()),
+        ("GuildDiscoveryMetadata", std::mem::size_of::<GuildDiscoveryMetadata>()),
         ("GuildEmojisUpdateEvent", std::mem::size_of::<GuildEmojisUpdateEvent>()),
         ("GuildId", std::mem::size_of::<GuildId>()),
         ("GuildInfo", std::mem::size_of::<GuildInfo>()),
@@ -75,6 +76,7 @@ pub fn print_ranking() {
         ("GuildMemberRemoveEvent", std::mem::size_of::<GuildMemberRemoveEvent>()),
         ("GuildMemberUpdateEvent", std::mem::size_of::<GuildMemberUpdateEvent>()),
         ("GuildMembersChunkEvent", std::mem::size_of::<GuildMembersChunkEvent>()),
+        ("GuildOnboarding", std::mem::size_of::<GuildOnboarding>()),
         ("GuildPreview", std::mem::size_of::<GuildPreview>()),
         ("GuildPrune", std::mem::size_of::<GuildPrune>()),
         ("GuildRoleCreateEvent", std::mem::size_of::<GuildRoleCreateEvent>()),
@@ -89,6 +91,7 @@ pub fn print_ranking() {
             std::mem::size_of::<GuildScheduledEventUserRemoveEvent>(),
         ),
         ("GuildStickersUpdateEvent", std::mem::size_of::<GuildStickersUpdateEvent>()),
+        ("GuildTemplate", std::mem::size_of::<GuildTemplate>()),
         ("GuildUpdateEvent", std::mem::size_of::<GuildUpdateEvent>()),
         ("GuildWelcomeChannel", std::mem::size_of::<GuildWelcomeChannel>()),
         ("GuildWelcomeScreen", std::mem::size_of::<GuildWelcomeScreen>()),
@@ -131,6 +134,8 @@ pub fn print_ranking() {
         ("MessageUpdateEvent", std::mem::size_of::<MessageUpdateEvent>()),
         ("ModalInteraction", std::mem::size_of::<ModalInteraction>()),
         ("ModalInteractionData", std::mem::size_of::<ModalInteractionData>()),
+        ("OnboardingPrompt", std::mem::size_of::<OnboardingPrompt>()),
+        ("OnboardingPromptOption", std::mem::size_of::<OnboardingPromptOption>()),
         ("Options", std::mem::size_of::<Options>()),
         ("PartialChannel", std::mem::size_of::<PartialChannel>()),
         ("PartialCurrentApplicationInfo", std::mem::size_of::<PartialCurrentApplicationInfo>()),
@@ -166,6 +171,8 @@ pub fn print_ranking() {
         ("SessionStartLimit", std::mem::size_of::<SessionStartLimit>()),
         ("ShardInfo", std::mem::size_of::<ShardInfo>()),
         ("SkuId", std::mem::size_of::<SkuId>()),
+        ("SoundboardSound", std::mem::size_of::<SoundboardSound>()),
+        ("SoundId", std::mem::size_of::<SoundId>()),
         ("StageInstance", std::mem::size_of::<StageInstance>()),
         ("StageInstanceCreateEvent", std::mem::size_of::<StageInstanceCreateEvent>()),
         ("StageInstanceDeleteEvent", std::mem::size_of::<StageInstanceDeleteEvent>()),
@@ -199,6 +206,7 @@ pub fn print_ranking() {
         ("UserId", std::mem::size_of::<UserId>()),
         ("UserPublicFlags", std::mem::size_of::<UserPublicFlags>()),
         ("UserUpdateEvent", std::mem::size_of::<UserUpdateEvent>()),
+        ("VanityInviteInfo", std::mem::size_of::<VanityInviteInfo>()),
         ("VoiceRegion", std::mem::size_of::<VoiceRegion>()),
         ("VoiceServerUpdateEvent", std::mem::size_of::<VoiceServerUpdateEvent>()),
         ("VoiceState", std::mem::size_of::<VoiceState>()),
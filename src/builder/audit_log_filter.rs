@@ -0,0 +1,110 @@
+#[cfg(feature = "http")]
+use super::Builder;
+#[cfg(feature = "http")]
+use crate::http::CacheHttp;
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
+use crate::model::guild::audit_log::Action;
+#[cfg(feature = "http")]
+use crate::model::guild::audit_log::AuditLogs;
+#[cfg(feature = "http")]
+use crate::model::id::GuildId;
+use crate::model::id::{AuditLogEntryId, UserId};
+
+/// Builds a request to the API to retrieve a guild's audit log entries.
+///
+/// All filters are optional. If none are specified, the most recent entries are returned.
+///
+/// # Examples
+///
+/// Retrieving the 10 most recent bans:
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// #
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let http: Http = unimplemented!();
+/// use serenity::builder::AuditLogFilter;
+/// use serenity::model::guild::audit_log::{Action, MemberAction};
+/// use serenity::model::id::GuildId;
+///
+/// let guild_id = GuildId::new(81384788765712384);
+///
+/// let filter = AuditLogFilter::new().action_type(Action::Member(MemberAction::BanAdd)).limit(10);
+/// let _audit_logs = guild_id.audit_log_entries(&http, filter).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/audit-log#get-guild-audit-log)
+#[derive(Clone, Copy, Debug, Default)]
+#[must_use]
+pub struct AuditLogFilter {
+    action_type: Option<Action>,
+    user_id: Option<UserId>,
+    before: Option<AuditLogEntryId>,
+    limit: Option<u8>,
+}
+
+impl AuditLogFilter {
+    /// Equivalent to [`Self::default`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Filters the returned entries by the type of action that was performed.
+    pub fn action_type(mut self, action_type: Action) -> Self {
+        self.action_type = Some(action_type);
+        self
+    }
+
+    /// Filters the returned entries by the user who performed the action.
+    pub fn user_id(mut self, user_id: impl Into<UserId>) -> Self {
+        self.user_id = Some(user_id.into());
+        self
+    }
+
+    /// Indicates to retrieve the entries before a specific entry, given its Id.
+    pub fn before(mut self, before: impl Into<AuditLogEntryId>) -> Self {
+        self.before = Some(before.into());
+        self
+    }
+
+    /// The maximum number of entries to retrieve.
+    ///
+    /// If this is not specified, a default value of 50 is used.
+    ///
+    /// **Note**: This field is capped to 100 entries due to a Discord limitation. If an amount
+    /// larger than 100 is supplied, it will be truncated.
+    pub fn limit(mut self, limit: u8) -> Self {
+        self.limit = Some(limit.min(100));
+        self
+    }
+}
+
+#[cfg(feature = "http")]
+#[async_trait::async_trait]
+impl Builder for AuditLogFilter {
+    type Context<'ctx> = GuildId;
+    type Built = AuditLogs;
+
+    /// Gets the guild's audit log entries matching the filter.
+    ///
+    /// **Note**: Requires the [View Audit Log] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [View Audit Log]: crate::model::permissions::Permissions::VIEW_AUDIT_LOG
+    async fn execute(
+        self,
+        cache_http: impl CacheHttp,
+        ctx: Self::Context<'_>,
+    ) -> Result<Self::Built> {
+        cache_http
+            .http()
+            .get_audit_logs(ctx, self.action_type, self.user_id, self.before, self.limit)
+            .await
+    }
+}
@@ -97,12 +97,16 @@ impl CreateAttachment {
     /// places.
     #[must_use]
     pub fn to_base64(&self) -> String {
-        let mut encoded = {
+        self.to_base64_with_mime("image/png")
+    }
+
+    /// Converts the stored data to a base64 data URI with the given MIME type.
+    pub(crate) fn to_base64_with_mime(&self, mime: &str) -> String {
+        let encoded = {
             use base64::Engine;
             base64::prelude::BASE64_STANDARD.encode(&self.data)
         };
-        encoded.insert_str(0, "data:image/png;base64,");
-        encoded
+        format!("data:{mime};base64,{encoded}")
     }
 
     /// Sets a description for the file (max 1024 characters).
@@ -266,6 +266,9 @@ impl Builder for CreateChannel<'_> {
     ///
     /// # Errors
     ///
+    /// Returns [`ModelError::NestedCategory`] if a [`ChannelType::Category`] channel is given a
+    /// parent category.
+    ///
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
     /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
     ///
@@ -275,6 +278,10 @@ impl Builder for CreateChannel<'_> {
         cache_http: impl CacheHttp,
         ctx: Self::Context<'_>,
     ) -> Result<Self::Built> {
+        if self.kind == ChannelType::Category && self.parent_id.is_some() {
+            return Err(Error::Model(ModelError::NestedCategory));
+        }
+
         #[cfg(feature = "cache")]
         crate::utils::user_has_guild_perms(&cache_http, ctx, Permissions::MANAGE_CHANNELS)?;
 
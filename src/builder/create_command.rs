@@ -323,6 +323,62 @@ pub struct CreateCommand {
 }
 
 impl CreateCommand {
+    /// Checks that the description matches the command type: chat input commands require a
+    /// non-empty description, while user and message commands must not have one, localized or
+    /// not.
+    ///
+    /// When editing a command, a missing description is left unchanged by Discord, so it is only
+    /// required when creating a command.
+    #[cfg(feature = "http")]
+    fn check_description(&self, editing: bool) -> StdResult<(), ModelError> {
+        let description = self.description.as_deref();
+        let valid = match self.kind.unwrap_or(CommandType::ChatInput) {
+            CommandType::ChatInput => match description {
+                Some(description) => !description.is_empty(),
+                None => editing,
+            },
+            CommandType::User | CommandType::Message => {
+                description.map_or(true, str::is_empty) && self.description_localizations.is_empty()
+            },
+            _ => true,
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(ModelError::InvalidCommandDescription)
+        }
+    }
+
+    /// Checks each localized name and description, so that a single invalid localization is
+    /// reported by its locale instead of failing the whole request.
+    #[cfg(feature = "http")]
+    fn check_localizations(&self) -> StdResult<(), ModelError> {
+        // Only chat input command names are restricted to lowercase words.
+        if self.kind.unwrap_or(CommandType::ChatInput) == CommandType::ChatInput {
+            let is_valid_char =
+                |c: char| (c.is_alphanumeric() || c == '-' || c == '_') && !c.is_uppercase();
+            let is_valid_name = |name: &str| {
+                (1..=32).contains(&name.chars().count()) && name.chars().all(is_valid_char)
+            };
+            if let Some((locale, _)) =
+                self.name_localizations.iter().find(|(_, name)| !is_valid_name(name))
+            {
+                return Err(ModelError::InvalidCommandNameLocalization(locale.clone()));
+            }
+        }
+
+        if let Some((locale, _)) = self
+            .description_localizations
+            .iter()
+            .find(|(_, description)| !(1..=100).contains(&description.chars().count()))
+        {
+            return Err(ModelError::InvalidCommandDescriptionLocalization(locale.clone()));
+        }
+
+        Ok(())
+    }
+
     /// Creates a new builder with the given name, leaving all other fields empty.
     pub fn new(name: impl Into<String>) -> Self {
         Self {
@@ -484,6 +540,14 @@ impl Builder for CreateCommand {
     ///
     /// # Errors
     ///
+    /// Returns [`ModelError::InvalidCommandDescription`] if a chat input command is created
+    /// without a description, or if a user or message command is given a non-empty or localized
+    /// one.
+    ///
+    /// Returns [`ModelError::InvalidCommandNameLocalization`] or
+    /// [`ModelError::InvalidCommandDescriptionLocalization`], containing the offending locale, if
+    /// a localized name or description is invalid.
+    ///
     /// Returns [`Error::Http`] if invalid data is given. See [Discord's docs] for more details.
     ///
     /// May also return [`Error::Json`] if there is an error in deserializing the API response.
@@ -494,6 +558,9 @@ impl Builder for CreateCommand {
         cache_http: impl CacheHttp,
         ctx: Self::Context<'_>,
     ) -> Result<Self::Built> {
+        self.check_description(ctx.1.is_some()).map_err(Error::Model)?;
+        self.check_localizations().map_err(Error::Model)?;
+
         let http = cache_http.http();
         match ctx {
             (Some(guild_id), Some(cmd_id)) => {
@@ -505,3 +572,82 @@ impl Builder for CreateCommand {
         }
     }
 }
+
+#[cfg(all(test, feature = "http"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn description() {
+        // Chat input commands need a description when created, but not when edited.
+        let command = CreateCommand::new("ping");
+        assert!(matches!(
+            command.check_description(false),
+            Err(ModelError::InvalidCommandDescription)
+        ));
+        assert!(command.check_description(true).is_ok());
+
+        let command = CreateCommand::new("ping").description("");
+        assert!(command.check_description(false).is_err());
+        assert!(command.check_description(true).is_err());
+
+        let command = CreateCommand::new("ping").description("Ping");
+        assert!(command.check_description(false).is_ok());
+        assert!(command.check_description(true).is_ok());
+
+        // User and message commands must not have a description.
+        for kind in [CommandType::User, CommandType::Message] {
+            let command = CreateCommand::new("Ping").kind(kind);
+            assert!(command.check_description(false).is_ok());
+            assert!(command.clone().description("").check_description(false).is_ok());
+            assert!(command.clone().description("Ping").check_description(false).is_err());
+            assert!(command.description("Ping").check_description(true).is_err());
+        }
+    }
+
+    #[test]
+    fn localizations() {
+        let command = CreateCommand::new("ping")
+            .description("Ping")
+            .name_localized("de", "ping")
+            .description_localized("de", "Ping");
+        assert!(command.check_localizations().is_ok());
+
+        let command = CreateCommand::new("ping").name_localized("de", "Ping");
+        assert!(matches!(
+            command.check_localizations(),
+            Err(ModelError::InvalidCommandNameLocalization(locale)) if locale == "de"
+        ));
+
+        let command = CreateCommand::new("ping").name_localized("fr", "a".repeat(33));
+        assert!(matches!(
+            command.check_localizations(),
+            Err(ModelError::InvalidCommandNameLocalization(locale)) if locale == "fr"
+        ));
+
+        let command = CreateCommand::new("ping").description_localized("es-ES", "");
+        assert!(matches!(
+            command.check_localizations(),
+            Err(ModelError::InvalidCommandDescriptionLocalization(locale)) if locale == "es-ES"
+        ));
+
+        let command = CreateCommand::new("ping").description_localized("ja", "a".repeat(101));
+        assert!(matches!(
+            command.check_localizations(),
+            Err(ModelError::InvalidCommandDescriptionLocalization(locale)) if locale == "ja"
+        ));
+
+        // Context menu command names may contain uppercase letters and spaces.
+        let command =
+            CreateCommand::new("Ping").kind(CommandType::User).name_localized("de", "Ping Mich");
+        assert!(command.check_localizations().is_ok());
+
+        let command = CreateCommand::new("Ping")
+            .kind(CommandType::Message)
+            .description_localized("de", "Ping");
+        assert!(matches!(
+            command.check_description(false),
+            Err(ModelError::InvalidCommandDescription)
+        ));
+    }
+}
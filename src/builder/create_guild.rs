@@ -0,0 +1,220 @@
+#[cfg(feature = "http")]
+use super::Builder;
+use super::{CreateAttachment, CreateChannel, EditRole};
+#[cfg(feature = "http")]
+use crate::http::CacheHttp;
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+/// A builder for creating a new guild, optionally with initial settings, roles and channels.
+///
+/// **Note**: This endpoint can only be used by bots in less than 10 guilds.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#create-guild).
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateGuild<'a> {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    icon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    verification_level: Option<VerificationLevel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_message_notifications: Option<DefaultMessageNotificationLevel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    explicit_content_filter: Option<ExplicitContentFilter>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    roles: Vec<CreateGuildRole<'a>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    channels: Vec<CreateGuildChannel<'a>>,
+}
+
+impl<'a> CreateGuild<'a> {
+    /// Creates a builder with the given name, leaving all other fields empty.
+    ///
+    /// **Note**: The name must be between (and including) 2-100 characters.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            icon: None,
+            verification_level: None,
+            default_message_notifications: None,
+            explicit_content_filter: None,
+            roles: Vec::new(),
+            channels: Vec::new(),
+        }
+    }
+
+    /// Set the name of the guild.
+    ///
+    /// **Note**: Must be between (and including) 2-100 characters.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Set the icon of the guild.
+    pub fn icon(mut self, icon: &CreateAttachment) -> Self {
+        self.icon = Some(icon.to_base64());
+        self
+    }
+
+    /// Set the verification level of the guild.
+    pub fn verification_level(mut self, verification_level: VerificationLevel) -> Self {
+        self.verification_level = Some(verification_level);
+        self
+    }
+
+    /// Set the default message notification level.
+    pub fn default_message_notifications(mut self, level: DefaultMessageNotificationLevel) -> Self {
+        self.default_message_notifications = Some(level);
+        self
+    }
+
+    /// Set the content filter level.
+    pub fn explicit_content_filter(mut self, level: ExplicitContentFilter) -> Self {
+        self.explicit_content_filter = Some(level);
+        self
+    }
+
+    /// Set the roles to create the guild with.
+    ///
+    /// **Note**: The first role is used to configure the `@everyone` role.
+    pub fn roles(mut self, roles: impl IntoIterator<Item = CreateGuildRole<'a>>) -> Self {
+        self.roles = roles.into_iter().collect();
+        self
+    }
+
+    /// Set the channels to create the guild with. If none are given, Discord creates default
+    /// channels.
+    pub fn channels(mut self, channels: impl IntoIterator<Item = CreateGuildChannel<'a>>) -> Self {
+        self.channels = channels.into_iter().collect();
+        self
+    }
+}
+
+/// A role to create along with a guild through [`CreateGuild::roles`].
+///
+/// The Id is a placeholder, which is replaced by Discord when the guild is created. It can be used
+/// to refer to the role in the [`CreateChannel::permissions`] of a [`CreateGuildChannel`] in the
+/// same request.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#create-guild).
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateGuildRole<'a> {
+    id: RoleId,
+    #[serde(flatten)]
+    role: EditRole<'a>,
+}
+
+impl<'a> CreateGuildRole<'a> {
+    /// Creates a role with the given placeholder Id and settings.
+    pub fn new(id: impl Into<RoleId>, role: EditRole<'a>) -> Self {
+        Self {
+            id: id.into(),
+            role,
+        }
+    }
+}
+
+/// A channel to create along with a guild through [`CreateGuild::channels`].
+///
+/// The Id is a placeholder, which is replaced by Discord when the guild is created. It can be used
+/// as the [`CreateChannel::category`] of another [`CreateGuildChannel`] in the same request.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#create-guild).
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateGuildChannel<'a> {
+    id: ChannelId,
+    #[serde(flatten)]
+    channel: CreateChannel<'a>,
+}
+
+impl<'a> CreateGuildChannel<'a> {
+    /// Creates a channel with the given placeholder Id and settings.
+    pub fn new(id: impl Into<ChannelId>, channel: CreateChannel<'a>) -> Self {
+        Self {
+            id: id.into(),
+            channel,
+        }
+    }
+}
+
+#[cfg(feature = "http")]
+#[async_trait::async_trait]
+impl Builder for CreateGuild<'_> {
+    type Context<'ctx> = ();
+    type Built = PartialGuild;
+
+    /// Creates a guild with the data provided.
+    ///
+    /// Only a [`PartialGuild`] will be immediately returned, and a full [`Guild`] will be received
+    /// over a [`Shard`], if at least one is running.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user cannot create a guild, or if invalid data is
+    /// given.
+    ///
+    /// [`Shard`]: crate::gateway::Shard
+    async fn execute(
+        self,
+        cache_http: impl CacheHttp,
+        _ctx: Self::Context<'_>,
+    ) -> Result<Self::Built> {
+        cache_http.http().create_guild(&self).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{json, to_value};
+
+    #[test]
+    fn placeholder_ids() {
+        let role_id = RoleId::new(2);
+        let category_id = ChannelId::new(3);
+        let overwrite = PermissionOverwrite {
+            allow: Permissions::VIEW_CHANNEL,
+            deny: Permissions::empty(),
+            kind: PermissionOverwriteType::Role(role_id),
+        };
+
+        let builder = CreateGuild::new("guild")
+            .roles([CreateGuildRole::new(role_id, EditRole::new().name("mods"))])
+            .channels([
+                CreateGuildChannel::new(
+                    category_id,
+                    CreateChannel::new("staff").kind(ChannelType::Category),
+                ),
+                CreateGuildChannel::new(
+                    ChannelId::new(4),
+                    CreateChannel::new("mod-chat").category(category_id).permissions([overwrite]),
+                ),
+            ]);
+
+        assert_eq!(
+            to_value(&builder).unwrap(),
+            json!({
+                "name": "guild",
+                "roles": [{"id": "2", "name": "mods"}],
+                "channels": [
+                    {"id": "3", "name": "staff", "type": 4},
+                    {
+                        "id": "4",
+                        "name": "mod-chat",
+                        "type": 0,
+                        "parent_id": "3",
+                        "permission_overwrites": [
+                            {"allow": "1024", "deny": "0", "id": "2", "type": 0},
+                        ],
+                    },
+                ],
+            })
+        );
+    }
+}
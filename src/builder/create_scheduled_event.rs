@@ -120,6 +120,40 @@ impl<'a> CreateScheduledEvent<'a> {
         self.audit_log_reason = Some(reason);
         self
     }
+
+    /// Checks that the fields required by the event type are set: stage and voice events require
+    /// a channel, while external events require a location and an end time, and no channel.
+    #[cfg(feature = "http")]
+    fn check_fields(&self) -> StdResult<(), ModelError> {
+        let has_location = self
+            .entity_metadata
+            .as_ref()
+            .and_then(|metadata| metadata.location.as_deref())
+            .is_some_and(|location| !location.is_empty());
+
+        let invalid_field = match self.entity_type {
+            ScheduledEventType::StageInstance | ScheduledEventType::Voice => {
+                self.channel_id.is_none().then_some("channel_id")
+            },
+            ScheduledEventType::External => {
+                if self.channel_id.is_some() {
+                    Some("channel_id")
+                } else if !has_location {
+                    Some("location")
+                } else if self.scheduled_end_time.is_none() {
+                    Some("end_time")
+                } else {
+                    None
+                }
+            },
+            _ => None,
+        };
+
+        match invalid_field {
+            Some(field) => Err(ModelError::InvalidScheduledEventField(field)),
+            None => Ok(()),
+        }
+    }
 }
 
 #[cfg(feature = "http")]
@@ -134,6 +168,9 @@ impl Builder for CreateScheduledEvent<'_> {
     ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::InvalidScheduledEventField`] if a field required by the event type
+    /// is missing, or a field not allowed for it is set.
+    ///
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
     /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
     ///
@@ -143,9 +180,54 @@ impl Builder for CreateScheduledEvent<'_> {
         cache_http: impl CacheHttp,
         ctx: Self::Context<'_>,
     ) -> Result<Self::Built> {
+        self.check_fields().map_err(Error::Model)?;
+
         #[cfg(feature = "cache")]
         crate::utils::user_has_guild_perms(&cache_http, ctx, Permissions::CREATE_EVENTS)?;
 
         cache_http.http().create_scheduled_event(ctx, &self, self.audit_log_reason).await
     }
 }
+
+#[cfg(all(test, feature = "http"))]
+mod tests {
+    use super::*;
+
+    fn event(kind: ScheduledEventType) -> CreateScheduledEvent<'static> {
+        CreateScheduledEvent::new(kind, "event", Timestamp::now())
+    }
+
+    fn invalid_field(event: &CreateScheduledEvent<'_>) -> Option<&'static str> {
+        match event.check_fields() {
+            Ok(()) => None,
+            Err(ModelError::InvalidScheduledEventField(field)) => Some(field),
+            Err(why) => panic!("unexpected error: {why:?}"),
+        }
+    }
+
+    #[test]
+    fn check_fields() {
+        for kind in [ScheduledEventType::StageInstance, ScheduledEventType::Voice] {
+            assert_eq!(invalid_field(&event(kind)), Some("channel_id"));
+            assert_eq!(invalid_field(&event(kind).channel_id(ChannelId::new(1))), None);
+        }
+
+        let external = || event(ScheduledEventType::External);
+        assert_eq!(invalid_field(&external()), Some("location"));
+        assert_eq!(invalid_field(&external().location("")), Some("location"));
+        assert_eq!(invalid_field(&external().location("here")), Some("end_time"));
+        assert_eq!(
+            invalid_field(&external().location("here").end_time(Timestamp::now())),
+            None
+        );
+        assert_eq!(
+            invalid_field(
+                &external()
+                    .location("here")
+                    .end_time(Timestamp::now())
+                    .channel_id(ChannelId::new(1))
+            ),
+            Some("channel_id")
+        );
+    }
+}
@@ -8,6 +8,10 @@ use crate::internal::prelude::*;
 #[cfg(feature = "http")]
 use crate::model::prelude::*;
 
+/// The maximum size of a sticker file, in bytes.
+#[cfg(feature = "http")]
+const STICKER_FILE_SIZE_LIMIT: u64 = 512 * 1024;
+
 /// A builder to create a guild sticker
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/sticker#create-guild-sticker)
@@ -59,7 +63,7 @@ impl<'a> CreateSticker<'a> {
 
     /// Set the sticker file. Replaces the current value as set in [`Self::new`].
     ///
-    /// **Note**: Must be a PNG, APNG, or Lottie JSON file, max 500 KB.
+    /// **Note**: Must be a PNG, APNG, GIF or Lottie JSON file, max 512 KiB.
     pub fn file(mut self, file: CreateAttachment) -> Self {
         self.file = file;
         self
@@ -70,6 +74,34 @@ impl<'a> CreateSticker<'a> {
         self.audit_log_reason = Some(reason);
         self
     }
+
+    /// Checks that the name, description and tags are within Discord's limits, and that the file
+    /// is in a supported format.
+    #[cfg(feature = "http")]
+    fn check_fields(&self) -> StdResult<(), ModelError> {
+        let name_len = self.name.chars().count();
+        if !(2..=30).contains(&name_len) {
+            return Err(ModelError::InvalidStickerName);
+        }
+        let description_len = self.description.chars().count();
+        if description_len == 1 || description_len > 100 {
+            return Err(ModelError::InvalidStickerDescription);
+        }
+        if self.tags.chars().count() > 200 {
+            return Err(ModelError::InvalidStickerTags);
+        }
+
+        // APNG files share the PNG signature, while Lottie stickers are JSON objects.
+        let data = &self.file.data;
+        let is_png = data.starts_with(b"\x89PNG\r\n\x1a\n");
+        let is_gif = data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a");
+        let is_lottie = data.iter().find(|b| !b.is_ascii_whitespace()) == Some(&b'{');
+        if !(is_png || is_gif || is_lottie) {
+            return Err(ModelError::InvalidStickerFormat);
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(feature = "http")]
@@ -84,6 +116,13 @@ impl Builder for CreateSticker<'_> {
     ///
     /// # Errors
     ///
+    /// Returns [`ModelError::InvalidStickerName`], [`ModelError::InvalidStickerDescription`] or
+    /// [`ModelError::InvalidStickerTags`] if the respective field is not within Discord's limits,
+    /// and [`Error::NotInRange`] if the sticker file is empty or larger than 512 KiB.
+    ///
+    /// Returns [`ModelError::InvalidStickerFormat`] if the sticker file is not a PNG, APNG, GIF or
+    /// Lottie JSON file.
+    ///
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
     /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
     ///
@@ -93,6 +132,13 @@ impl Builder for CreateSticker<'_> {
         cache_http: impl CacheHttp,
         ctx: Self::Context<'_>,
     ) -> Result<Self::Built> {
+        let size = self.file.data.len() as u64;
+        if !(1..=STICKER_FILE_SIZE_LIMIT).contains(&size) {
+            return Err(Error::NotInRange("file", size, 1, STICKER_FILE_SIZE_LIMIT));
+        }
+
+        self.check_fields().map_err(Error::Model)?;
+
         #[cfg(feature = "cache")]
         crate::utils::user_has_guild_perms(
             &cache_http,
@@ -104,3 +150,54 @@ impl Builder for CreateSticker<'_> {
         cache_http.http().create_sticker(ctx, map, self.file, self.audit_log_reason).await
     }
 }
+
+#[cfg(all(test, feature = "http"))]
+mod tests {
+    use super::*;
+
+    fn sticker(name: &str) -> CreateSticker<'static> {
+        CreateSticker::new(name, CreateAttachment::bytes(&b"\x89PNG\r\n\x1a\n"[..], "sticker.png"))
+    }
+
+    #[test]
+    fn check_fields() {
+        assert!(sticker("ok").check_fields().is_ok());
+        assert!(sticker(&"a".repeat(30)).check_fields().is_ok());
+
+        for name in ["a", &"a".repeat(31)] {
+            assert!(matches!(
+                sticker(name).check_fields(),
+                Err(ModelError::InvalidStickerName)
+            ));
+        }
+
+        // The description must be either empty or 2-100 characters long.
+        for description in ["", "ab", &"a".repeat(100)] {
+            assert!(sticker("name").description(description).check_fields().is_ok());
+        }
+        for description in ["a", &"a".repeat(101)] {
+            assert!(matches!(
+                sticker("name").description(description).check_fields(),
+                Err(ModelError::InvalidStickerDescription)
+            ));
+        }
+
+        assert!(sticker("name").tags("a".repeat(200)).check_fields().is_ok());
+        assert!(matches!(
+            sticker("name").tags("a".repeat(201)).check_fields(),
+            Err(ModelError::InvalidStickerTags)
+        ));
+
+        for data in [&b"GIF89a"[..], b" {\"v\": \"5.5.2\"}"] {
+            let file = CreateAttachment::bytes(data, "sticker");
+            assert!(sticker("name").file(file).check_fields().is_ok());
+        }
+        for data in [&b"\xff\xd8\xff"[..], b""] {
+            let file = CreateAttachment::bytes(data, "sticker.jpg");
+            assert!(matches!(
+                sticker("name").file(file).check_fields(),
+                Err(ModelError::InvalidStickerFormat)
+            ));
+        }
+    }
+}
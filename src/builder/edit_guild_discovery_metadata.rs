@@ -0,0 +1,95 @@
+#[cfg(feature = "http")]
+use super::Builder;
+#[cfg(feature = "http")]
+use crate::http::CacheHttp;
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
+#[cfg(feature = "http")]
+use crate::model::prelude::*;
+
+/// The maximum number of discovery search keywords of a guild.
+#[cfg(feature = "http")]
+const DISCOVERY_KEYWORDS_MAX: u64 = 10;
+
+/// A builder to edit the discovery metadata of a guild.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/discovery#modify-guild-discovery-metadata).
+#[derive(Clone, Debug, Default, Serialize)]
+#[must_use]
+pub struct EditGuildDiscoveryMetadata<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    primary_category_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    keywords: Option<Option<Vec<String>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emoji_discoverability_enabled: Option<bool>,
+
+    #[serde(skip)]
+    audit_log_reason: Option<&'a str>,
+}
+
+impl<'a> EditGuildDiscoveryMetadata<'a> {
+    /// Equivalent to [`Self::default`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the Id of the primary discovery category of the guild.
+    pub fn primary_category_id(mut self, id: u64) -> Self {
+        self.primary_category_id = Some(id);
+        self
+    }
+
+    /// Sets the discovery search keywords of the guild. Pass [`None`] to remove all keywords.
+    ///
+    /// **Note**: A guild can have up to 10 keywords.
+    pub fn keywords(mut self, keywords: Option<Vec<String>>) -> Self {
+        self.keywords = Some(keywords);
+        self
+    }
+
+    /// Sets whether the guild is shown as a source through custom emoji usage.
+    pub fn emoji_discoverability_enabled(mut self, enabled: bool) -> Self {
+        self.emoji_discoverability_enabled = Some(enabled);
+        self
+    }
+
+    /// Sets the request's audit log reason.
+    pub fn audit_log_reason(mut self, reason: &'a str) -> Self {
+        self.audit_log_reason = Some(reason);
+        self
+    }
+}
+
+#[cfg(feature = "http")]
+#[async_trait::async_trait]
+impl Builder for EditGuildDiscoveryMetadata<'_> {
+    type Context<'ctx> = GuildId;
+    type Built = GuildDiscoveryMetadata;
+
+    /// Edits the guild's discovery metadata.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotInRange`] if more than 10 keywords are set.
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    async fn execute(
+        self,
+        cache_http: impl CacheHttp,
+        ctx: Self::Context<'_>,
+    ) -> Result<Self::Built> {
+        if let Some(Some(keywords)) = &self.keywords {
+            let amount = keywords.len() as u64;
+            if amount > DISCOVERY_KEYWORDS_MAX {
+                return Err(Error::NotInRange("keywords", amount, 0, DISCOVERY_KEYWORDS_MAX));
+            }
+        }
+
+        cache_http.http().edit_guild_discovery_metadata(ctx, &self, self.audit_log_reason).await
+    }
+}
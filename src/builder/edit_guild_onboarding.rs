@@ -0,0 +1,231 @@
+#[cfg(feature = "http")]
+use super::Builder;
+#[cfg(feature = "http")]
+use crate::http::CacheHttp;
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+/// A builder to edit the onboarding flow of a guild.
+///
+/// **Note**: Any field that is set replaces the current value entirely. To edit a single prompt,
+/// start from the guild's current prompts, which can be converted into [`CreateOnboardingPrompt`]s
+/// via [`From`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#modify-guild-onboarding)
+#[derive(Clone, Debug, Default, Serialize)]
+#[must_use]
+pub struct EditGuildOnboarding<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prompts: Option<Vec<CreateOnboardingPrompt>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_channel_ids: Option<Vec<ChannelId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mode: Option<OnboardingMode>,
+
+    #[serde(skip)]
+    audit_log_reason: Option<&'a str>,
+}
+
+impl<'a> EditGuildOnboarding<'a> {
+    /// Equivalent to [`Self::default`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the prompts shown during onboarding and in the customize community tab.
+    pub fn prompts(mut self, prompts: Vec<CreateOnboardingPrompt>) -> Self {
+        self.prompts = Some(prompts);
+        self
+    }
+
+    /// Sets the channels that members get opted into automatically.
+    pub fn default_channel_ids(
+        mut self,
+        channel_ids: impl IntoIterator<Item = impl Into<ChannelId>>,
+    ) -> Self {
+        self.default_channel_ids = Some(channel_ids.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Whether onboarding is enabled in the guild.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// Sets the criteria used to satisfy the onboarding constraints required for enabling it.
+    pub fn mode(mut self, mode: OnboardingMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Sets the request's audit log reason.
+    pub fn audit_log_reason(mut self, reason: &'a str) -> Self {
+        self.audit_log_reason = Some(reason);
+        self
+    }
+}
+
+#[cfg(feature = "http")]
+#[async_trait::async_trait]
+impl Builder for EditGuildOnboarding<'_> {
+    type Context<'ctx> = GuildId;
+    type Built = GuildOnboarding;
+
+    /// Edits the guild's onboarding flow.
+    ///
+    /// **Note**: Requires the [Manage Guild] and [Manage Roles] permissions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the resulting flow
+    /// does not satisfy Discord's onboarding constraints.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    /// [Manage Roles]: Permissions::MANAGE_ROLES
+    async fn execute(
+        self,
+        cache_http: impl CacheHttp,
+        ctx: Self::Context<'_>,
+    ) -> Result<Self::Built> {
+        cache_http.http().edit_guild_onboarding(ctx, &self, self.audit_log_reason).await
+    }
+}
+
+/// A builder for creating an [`OnboardingPrompt`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object-onboarding-prompt-structure)
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateOnboardingPrompt(OnboardingPrompt);
+
+impl CreateOnboardingPrompt {
+    /// Creates a multiple choice prompt with the given Id and title.
+    ///
+    /// **Note**: Discord requires new prompts to be sent with an Id, which may be any snowflake.
+    pub fn new(id: impl Into<OnboardingPromptId>, title: impl Into<String>) -> Self {
+        Self(OnboardingPrompt {
+            id: id.into(),
+            kind: OnboardingPromptType::MultipleChoice,
+            options: Vec::new(),
+            title: title.into(),
+            single_select: false,
+            required: false,
+            in_onboarding: true,
+        })
+    }
+
+    /// The type of the prompt.
+    pub fn kind(mut self, kind: OnboardingPromptType) -> Self {
+        self.0.kind = kind;
+        self
+    }
+
+    /// The title of the prompt.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.0.title = title.into();
+        self
+    }
+
+    /// Adds an option to the prompt.
+    pub fn add_option(mut self, option: CreateOnboardingPromptOption) -> Self {
+        self.0.options.push(option.0);
+        self
+    }
+
+    /// Sets the options of the prompt, replacing the current ones.
+    pub fn options(mut self, options: Vec<CreateOnboardingPromptOption>) -> Self {
+        self.0.options = options.into_iter().map(|option| option.0).collect();
+        self
+    }
+
+    /// Whether users are limited to selecting a single option.
+    pub fn single_select(mut self, single_select: bool) -> Self {
+        self.0.single_select = single_select;
+        self
+    }
+
+    /// Whether the prompt is required before a user completes the onboarding flow.
+    pub fn required(mut self, required: bool) -> Self {
+        self.0.required = required;
+        self
+    }
+
+    /// Whether the prompt is present in the onboarding flow, rather than only in the customize
+    /// community tab.
+    pub fn in_onboarding(mut self, in_onboarding: bool) -> Self {
+        self.0.in_onboarding = in_onboarding;
+        self
+    }
+}
+
+impl From<OnboardingPrompt> for CreateOnboardingPrompt {
+    fn from(prompt: OnboardingPrompt) -> Self {
+        Self(prompt)
+    }
+}
+
+/// A builder for creating an [`OnboardingPromptOption`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object-prompt-option-structure)
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateOnboardingPromptOption(OnboardingPromptOption);
+
+impl CreateOnboardingPromptOption {
+    /// Creates an option with the given Id and title.
+    ///
+    /// **Note**: Discord requires new options to be sent with an Id, which may be any snowflake.
+    pub fn new(id: impl Into<OnboardingPromptOptionId>, title: impl Into<String>) -> Self {
+        Self(OnboardingPromptOption {
+            id: id.into(),
+            channel_ids: Vec::new(),
+            role_ids: Vec::new(),
+            emoji: None,
+            title: title.into(),
+            description: None,
+        })
+    }
+
+    /// The channels a member is added to when the option is selected.
+    pub fn channel_ids(
+        mut self,
+        channel_ids: impl IntoIterator<Item = impl Into<ChannelId>>,
+    ) -> Self {
+        self.0.channel_ids = channel_ids.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// The roles assigned to a member when the option is selected.
+    pub fn role_ids(mut self, role_ids: impl IntoIterator<Item = impl Into<RoleId>>) -> Self {
+        self.0.role_ids = role_ids.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// The emoji shown for the option.
+    pub fn emoji(mut self, emoji: impl Into<ReactionType>) -> Self {
+        self.0.emoji = Some(emoji.into());
+        self
+    }
+
+    /// The title of the option.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.0.title = title.into();
+        self
+    }
+
+    /// The description of the option.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = Some(description.into());
+        self
+    }
+}
+
+impl From<OnboardingPromptOption> for CreateOnboardingPromptOption {
+    fn from(option: OnboardingPromptOption) -> Self {
+        Self(option)
+    }
+}
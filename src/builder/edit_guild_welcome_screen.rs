@@ -6,6 +6,10 @@ use crate::http::CacheHttp;
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
 
+/// The maximum number of channels that can be linked in a welcome screen.
+#[cfg(feature = "http")]
+const WELCOME_CHANNELS_MAX: u64 = 5;
+
 /// A builder to edit the welcome screen of a guild
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/guild#modify-guild-welcome-screen)
@@ -41,12 +45,17 @@ impl<'a> EditGuildWelcomeScreen<'a> {
         self
     }
 
+    /// Adds a channel to link in the welcome screen.
+    ///
+    /// **Note**: A welcome screen can link up to 5 channels.
     pub fn add_welcome_channel(mut self, channel: CreateGuildWelcomeChannel) -> Self {
         self.welcome_channels.push(channel);
         self
     }
 
     /// Channels linked in the welcome screen and their display options
+    ///
+    /// **Note**: A welcome screen can link up to 5 channels.
     pub fn set_welcome_channels(mut self, channels: Vec<CreateGuildWelcomeChannel>) -> Self {
         self.welcome_channels = channels;
         self
@@ -71,6 +80,8 @@ impl Builder for EditGuildWelcomeScreen<'_> {
     ///
     /// # Errors
     ///
+    /// Returns [`Error::NotInRange`] if more than 5 welcome channels are set.
+    ///
     /// Returns [`Error::Http`] if the current user lacks permission.
     ///
     /// [Manage Guild]: Permissions::MANAGE_GUILD
@@ -79,6 +90,11 @@ impl Builder for EditGuildWelcomeScreen<'_> {
         cache_http: impl CacheHttp,
         ctx: Self::Context<'_>,
     ) -> Result<Self::Built> {
+        let channels = self.welcome_channels.len() as u64;
+        if channels > WELCOME_CHANNELS_MAX {
+            return Err(Error::NotInRange("welcome_channels", channels, 0, WELCOME_CHANNELS_MAX));
+        }
+
         cache_http.http().edit_guild_welcome_screen(ctx, &self, self.audit_log_reason).await
     }
 }
@@ -37,6 +37,7 @@ pub(crate) fn check_overflow(len: usize, max: usize) -> StdResult<(), usize> {
 }
 
 mod add_member;
+mod audit_log_filter;
 mod bot_auth_parameters;
 mod create_allowed_mentions;
 mod create_attachment;
@@ -47,12 +48,14 @@ mod create_components;
 mod create_embed;
 mod create_forum_post;
 mod create_forum_tag;
+mod create_guild;
 mod create_interaction_response;
 mod create_interaction_response_followup;
 mod create_invite;
 mod create_message;
 pub mod create_poll;
 mod create_scheduled_event;
+mod create_soundboard_sound;
 mod create_stage_instance;
 mod create_sticker;
 mod create_thread;
@@ -60,6 +63,8 @@ mod create_webhook;
 mod edit_automod_rule;
 mod edit_channel;
 mod edit_guild;
+mod edit_guild_discovery_metadata;
+mod edit_guild_onboarding;
 mod edit_guild_welcome_screen;
 mod edit_guild_widget;
 mod edit_interaction_response;
@@ -68,6 +73,7 @@ mod edit_message;
 mod edit_profile;
 mod edit_role;
 mod edit_scheduled_event;
+mod edit_soundboard_sound;
 mod edit_stage_instance;
 mod edit_sticker;
 mod edit_thread;
@@ -79,6 +85,7 @@ mod get_entitlements;
 mod get_messages;
 
 pub use add_member::*;
+pub use audit_log_filter::*;
 pub use bot_auth_parameters::*;
 pub use create_allowed_mentions::*;
 pub use create_attachment::*;
@@ -89,12 +96,14 @@ pub use create_components::*;
 pub use create_embed::*;
 pub use create_forum_post::*;
 pub use create_forum_tag::*;
+pub use create_guild::*;
 pub use create_interaction_response::*;
 pub use create_interaction_response_followup::*;
 pub use create_invite::*;
 pub use create_message::*;
 pub use create_poll::{CreatePoll, CreatePollAnswer};
 pub use create_scheduled_event::*;
+pub use create_soundboard_sound::*;
 pub use create_stage_instance::*;
 pub use create_sticker::*;
 pub use create_thread::*;
@@ -102,6 +111,8 @@ pub use create_webhook::*;
 pub use edit_automod_rule::*;
 pub use edit_channel::*;
 pub use edit_guild::*;
+pub use edit_guild_discovery_metadata::*;
+pub use edit_guild_onboarding::*;
 pub use edit_guild_welcome_screen::*;
 pub use edit_guild_widget::*;
 pub use edit_interaction_response::*;
@@ -110,6 +121,7 @@ pub use edit_message::*;
 pub use edit_profile::*;
 pub use edit_role::*;
 pub use edit_scheduled_event::*;
+pub use edit_soundboard_sound::*;
 pub use edit_stage_instance::*;
 pub use edit_sticker::*;
 pub use edit_thread::*;
@@ -33,7 +33,7 @@ use crate::model::event::{
 use crate::model::gateway::ShardInfo;
 use crate::model::guild::{Guild, GuildMemberFlags, Member, Role};
 use crate::model::id::ShardId;
-use crate::model::user::{CurrentUser, OnlineStatus};
+use crate::model::user::CurrentUser;
 use crate::model::voice::VoiceState;
 
 impl CacheUpdate for ChannelCreateEvent {
@@ -99,6 +99,7 @@ impl CacheUpdate for GuildCreateEvent {
     fn update(&mut self, cache: &Cache) -> Option<()> {
         cache.unavailable_guilds.remove(&self.guild.id);
         let mut guild = self.guild.clone();
+        guild.refresh_sorted_role_ids();
 
         for (user_id, member) in &mut guild.members {
             cache.update_user_entry(&member.user);
@@ -261,10 +262,10 @@ impl CacheUpdate for GuildRoleCreateEvent {
     type Output = ();
 
     fn update(&mut self, cache: &Cache) -> Option<()> {
-        cache
-            .guilds
-            .get_mut(&self.role.guild_id)
-            .map(|mut g| g.roles.insert(self.role.id, self.role.clone()));
+        if let Some(mut guild) = cache.guilds.get_mut(&self.role.guild_id) {
+            guild.roles.insert(self.role.id, self.role.clone());
+            guild.refresh_sorted_role_ids();
+        }
 
         None
     }
@@ -274,7 +275,10 @@ impl CacheUpdate for GuildRoleDeleteEvent {
     type Output = Role;
 
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
-        cache.guilds.get_mut(&self.guild_id).and_then(|mut g| g.roles.remove(&self.role_id))
+        let mut guild = cache.guilds.get_mut(&self.guild_id)?;
+        let role = guild.roles.remove(&self.role_id);
+        guild.refresh_sorted_role_ids();
+        role
     }
 }
 
@@ -284,7 +288,9 @@ impl CacheUpdate for GuildRoleUpdateEvent {
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
         if let Some(mut guild) = cache.guilds.get_mut(&self.role.guild_id) {
             if let Some(role) = guild.roles.get_mut(&self.role.id) {
-                return Some(std::mem::replace(role, self.role.clone()));
+                let old = std::mem::replace(role, self.role.clone());
+                guild.refresh_sorted_role_ids();
+                return Some(old);
             }
         }
 
@@ -317,6 +323,7 @@ impl CacheUpdate for GuildUpdateEvent {
             guild.name.clone_from(&self.guild.name);
             guild.owner_id.clone_from(&self.guild.owner_id);
             guild.roles.clone_from(&self.guild.roles);
+            guild.refresh_sorted_role_ids();
             guild.splash.clone_from(&self.guild.splash);
             guild.vanity_url_code.clone_from(&self.guild.vanity_url_code);
             guild.welcome_screen.clone_from(&self.guild.welcome_screen);
@@ -430,12 +437,7 @@ impl CacheUpdate for PresenceUpdateEvent {
 
         if let Some(guild_id) = self.presence.guild_id {
             if let Some(mut guild) = cache.guilds.get_mut(&guild_id) {
-                // If the member went offline, remove them from the presence list.
-                if self.presence.status == OnlineStatus::Offline {
-                    guild.presences.remove(&self.presence.user.id);
-                } else {
-                    guild.presences.insert(self.presence.user.id, self.presence.clone());
-                }
+                guild.apply_presence(self.presence.clone());
 
                 // Create a partial member instance out of the presence update data.
                 if let Some(user) = self.presence.user.to_user() {
@@ -571,13 +573,7 @@ impl CacheUpdate for VoiceStateUpdateEvent {
                     guild.members.insert(member.user.id, member.clone());
                 }
 
-                if self.voice_state.channel_id.is_some() {
-                    // Update or add to the voice state list
-                    guild.voice_states.insert(self.voice_state.user_id, self.voice_state.clone())
-                } else {
-                    // Remove the user from the voice state list
-                    guild.voice_states.remove(&self.voice_state.user_id)
-                }
+                guild.apply_voice_state(self.voice_state.clone())
             } else {
                 None
             }
@@ -28,6 +28,8 @@ use std::ops::Range;
 use std::sync::Arc;
 #[cfg(feature = "framework")]
 use std::sync::OnceLock;
+#[cfg(feature = "gateway")]
+use std::time::Duration;
 
 use futures::channel::mpsc::UnboundedReceiver as Receiver;
 use futures::future::BoxFuture;
@@ -52,7 +54,7 @@ use crate::framework::Framework;
 use crate::gateway::VoiceGatewayManager;
 use crate::gateway::{ActivityData, PresenceData};
 #[cfg(feature = "gateway")]
-use crate::gateway::{ShardManager, ShardManagerOptions};
+use crate::gateway::{ShardManager, ShardManagerOptions, CONNECT_TIMEOUT};
 use crate::http::Http;
 use crate::internal::prelude::*;
 #[cfg(feature = "gateway")]
@@ -76,6 +78,8 @@ pub struct ClientBuilder {
     event_handlers: Vec<Arc<dyn EventHandler>>,
     raw_event_handlers: Vec<Arc<dyn RawEventHandler>>,
     presence: PresenceData,
+    connect_timeout: Duration,
+    log_outgoing: bool,
 }
 
 #[cfg(feature = "gateway")]
@@ -94,6 +98,8 @@ impl ClientBuilder {
             event_handlers: vec![],
             raw_event_handlers: vec![],
             presence: PresenceData::default(),
+            connect_timeout: CONNECT_TIMEOUT,
+            log_outgoing: false,
         }
     }
 
@@ -322,6 +328,33 @@ impl ClientBuilder {
     pub fn get_presence(&self) -> &PresenceData {
         &self.presence
     }
+
+    /// Sets how long shards wait for the WebSocket connection to the gateway to be established,
+    /// both when starting and when reconnecting. Defaults to 20 seconds.
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = connect_timeout;
+
+        self
+    }
+
+    /// Gets the connection timeout. See [`Self::connect_timeout`] for more info.
+    pub fn get_connect_timeout(&self) -> Duration {
+        self.connect_timeout
+    }
+
+    /// Sets whether shards log every outgoing gateway command at the debug level, with the token
+    /// redacted. Defaults to `false`.
+    pub fn log_outgoing(mut self, log_outgoing: bool) -> Self {
+        self.log_outgoing = log_outgoing;
+
+        self
+    }
+
+    /// Gets whether outgoing gateway commands are logged. See [`Self::log_outgoing`] for more
+    /// info.
+    pub fn get_log_outgoing(&self) -> bool {
+        self.log_outgoing
+    }
 }
 
 #[cfg(feature = "gateway")]
@@ -339,6 +372,8 @@ impl IntoFuture for ClientBuilder {
         let raw_event_handlers = self.raw_event_handlers;
         let intents = self.intents;
         let presence = self.presence;
+        let connect_timeout = self.connect_timeout;
+        let log_outgoing = self.log_outgoing;
 
         let mut http = self.http;
 
@@ -371,7 +406,7 @@ impl IntoFuture for ClientBuilder {
 
             #[cfg(feature = "framework")]
             let framework_cell = Arc::new(OnceLock::new());
-            let (shard_manager, shard_manager_ret_value) = ShardManager::new(ShardManagerOptions {
+            let options = ShardManagerOptions {
                 data: Arc::clone(&data),
                 event_handlers,
                 raw_event_handlers,
@@ -388,7 +423,10 @@ impl IntoFuture for ClientBuilder {
                 http: Arc::clone(&http),
                 intents,
                 presence: Some(presence),
-            });
+            };
+            let (shard_manager, shard_manager_ret_value) =
+                ShardManager::with_connect_timeout(options, connect_timeout);
+            shard_manager.set_log_outgoing(log_outgoing);
 
             let client = Client {
                 data,
@@ -21,6 +21,15 @@ pub const MESSAGE_CODE_LIMIT: usize = 2000;
 /// The maximum number of members the bot can fetch at once
 pub const MEMBER_FETCH_LIMIT: u64 = 1000;
 
+/// The maximum slowmode, in seconds, that can be set on a channel.
+pub const RATE_LIMIT_PER_USER_MAX: u16 = 21600;
+
+/// The maximum duration, in seconds, that a member can be timed out for.
+pub const TIMEOUT_MAX_SECS: u64 = 28 * 24 * 60 * 60;
+
+/// The maximum age, in seconds, of the messages that can be deleted when banning a member.
+pub const BAN_DELETE_MESSAGE_SECONDS_MAX: u32 = 7 * 24 * 60 * 60;
+
 /// The [UserAgent] sent along with every request.
 ///
 /// [UserAgent]: ::reqwest::header::USER_AGENT
@@ -1,5 +1,5 @@
 use std::collections::{HashMap, VecDeque};
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::Arc;
 #[cfg(feature = "framework")]
 use std::sync::OnceLock;
@@ -20,7 +20,7 @@ use crate::cache::Cache;
 use crate::client::{EventHandler, RawEventHandler};
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
-use crate::gateway::{ConnectionStage, GatewayError, PresenceData};
+use crate::gateway::{ConnectionStage, GatewayError, PresenceData, CONNECT_TIMEOUT};
 use crate::http::Http;
 use crate::internal::prelude::*;
 use crate::internal::tokio::spawn_named;
@@ -116,6 +116,10 @@ pub struct ShardManager {
     shard_shutdown: Mutex<Receiver<ShardId>>,
     shard_shutdown_send: Sender<ShardId>,
     gateway_intents: GatewayIntents,
+    /// How long shards wait for the WebSocket connection to be established.
+    pub(super) connect_timeout: Duration,
+    /// Whether shards log every outgoing gateway command.
+    pub(super) log_outgoing: AtomicBool,
 }
 
 impl ShardManager {
@@ -123,6 +127,19 @@ impl ShardManager {
     /// separate thread.
     #[must_use]
     pub fn new(opt: ShardManagerOptions) -> (Arc<Self>, Receiver<Result<(), GatewayError>>) {
+        Self::with_connect_timeout(opt, CONNECT_TIMEOUT)
+    }
+
+    /// Creates a new shard manager like [`Self::new`], whose shards wait at most
+    /// `connect_timeout` for the WebSocket connection to be established. See
+    /// [`Shard::with_connect_timeout`].
+    ///
+    /// [`Shard::with_connect_timeout`]: crate::gateway::Shard::with_connect_timeout
+    #[must_use]
+    pub fn with_connect_timeout(
+        opt: ShardManagerOptions,
+        connect_timeout: Duration,
+    ) -> (Arc<Self>, Receiver<Result<(), GatewayError>>) {
         let (return_value_tx, return_value_rx) = mpsc::unbounded();
         let (shard_queue_tx, shard_queue_rx) = mpsc::unbounded();
 
@@ -139,6 +156,8 @@ impl ShardManager {
             shard_shutdown_send: shutdown_send,
             runners: Arc::clone(&runners),
             gateway_intents: opt.intents,
+            connect_timeout,
+            log_outgoing: AtomicBool::new(false),
         });
 
         let mut shard_queuer = ShardQueuer {
@@ -339,6 +358,14 @@ impl ShardManager {
         self.gateway_intents
     }
 
+    /// Enables or disables logging of every outgoing gateway command for shards started after
+    /// this call. See [`Shard::set_log_outgoing`].
+    ///
+    /// [`Shard::set_log_outgoing`]: crate::gateway::Shard::set_log_outgoing
+    pub fn set_log_outgoing(&self, enabled: bool) {
+        self.log_outgoing.store(enabled, Ordering::Relaxed);
+    }
+
     pub async fn return_with_value(&self, ret: Result<(), GatewayError>) {
         if let Err(e) = self.return_value_tx.lock().await.send(ret).await {
             tracing::warn!("failed to send return value: {}", e);
@@ -1,4 +1,5 @@
 use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 #[cfg(feature = "framework")]
 use std::sync::OnceLock;
@@ -166,14 +167,16 @@ impl ShardQueuer {
     async fn start(&mut self, id: ShardId, total: u32) -> Result<()> {
         let shard_info = ShardInfo::new(id, total);
 
-        let mut shard = Shard::new(
+        let mut shard = Shard::with_connect_timeout(
             Arc::clone(&self.ws_url),
             self.http.token(),
             shard_info,
             self.intents,
             self.presence.clone(),
+            self.manager.connect_timeout,
         )
         .await?;
+        shard.set_log_outgoing(self.manager.log_outgoing.load(Ordering::Relaxed));
 
         let cloned_http = Arc::clone(&self.http);
         shard.set_application_id_callback(move |id| cloned_http.set_application_id(id));
@@ -41,6 +41,8 @@ pub enum Error {
     ///
     /// This limit is currently 2500 guilds per shard.
     OverloadedShard,
+    /// The WebSocket connection could not be established in time.
+    ConnectionTimeout,
     /// Failed to reconnect after a number of attempts.
     ReconnectFailure,
     /// When undocumented gateway intents are provided.
@@ -65,6 +67,7 @@ impl fmt::Display for Error {
             Self::NoAuthentication => f.write_str("Sent no authentication"),
             Self::NoSessionId => f.write_str("No Session Id present when required"),
             Self::OverloadedShard => f.write_str("Shard has too many guilds"),
+            Self::ConnectionTimeout => f.write_str("Timed out connecting to the gateway"),
             Self::ReconnectFailure => f.write_str("Failed to Reconnect"),
             Self::InvalidGatewayIntents => f.write_str("Invalid gateway intents were provided"),
             Self::DisallowedGatewayIntents => {
@@ -54,6 +54,8 @@ pub use self::bridge::*;
 pub use self::error::Error as GatewayError;
 pub use self::shard::Shard;
 pub use self::ws::WsClient;
+#[cfg(feature = "client")]
+pub(crate) use self::ws::CONNECT_TIMEOUT;
 #[cfg(feature = "http")]
 use crate::internal::prelude::*;
 use crate::model::gateway::{Activity, ActivityType};
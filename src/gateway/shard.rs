@@ -7,6 +7,7 @@ use tokio_tungstenite::tungstenite::protocol::frame::CloseFrame;
 use tracing::{debug, error, info, instrument, trace, warn};
 use url::Url;
 
+use super::ws::CONNECT_TIMEOUT;
 use super::{
     ActivityData,
     ChunkGuildFilter,
@@ -75,6 +76,7 @@ pub struct Shard {
     pub started: Instant,
     pub token: String,
     ws_url: Arc<Mutex<String>>,
+    connect_timeout: StdDuration,
     pub intents: GatewayIntents,
 }
 
@@ -125,9 +127,29 @@ impl Shard {
         shard_info: ShardInfo,
         intents: GatewayIntents,
         presence: Option<PresenceData>,
+    ) -> Result<Shard> {
+        Self::with_connect_timeout(ws_url, token, shard_info, intents, presence, CONNECT_TIMEOUT)
+            .await
+    }
+
+    /// Instantiates a new instance of a Shard like [`Self::new`], waiting at most
+    /// `connect_timeout` for the WebSocket connection to be established, both now and when
+    /// reconnecting. See [`Self::set_connect_timeout`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GatewayError::ConnectionTimeout`] if the connection is not established in time.
+    /// Otherwise, see [`Self::new`].
+    pub async fn with_connect_timeout(
+        ws_url: Arc<Mutex<String>>,
+        token: &str,
+        shard_info: ShardInfo,
+        intents: GatewayIntents,
+        presence: Option<PresenceData>,
+        connect_timeout: StdDuration,
     ) -> Result<Shard> {
         let url = ws_url.lock().await.clone();
-        let client = connect(&url).await?;
+        let client = connect(&url, connect_timeout).await?;
 
         let presence = presence.unwrap_or_default();
         let last_heartbeat_sent = None;
@@ -153,6 +175,7 @@ impl Shard {
             session_id,
             shard_info,
             ws_url,
+            connect_timeout,
             intents,
         })
     }
@@ -167,6 +190,21 @@ impl Shard {
         self.application_id_callback = Some(Box::new(callback));
     }
 
+    /// Sets how long to wait for the WebSocket connection to be established when reconnecting,
+    /// after which [`GatewayError::ConnectionTimeout`] is returned. Defaults to 20 seconds.
+    ///
+    /// To also apply a timeout to the initial connection, construct the shard with
+    /// [`Self::with_connect_timeout`].
+    pub fn set_connect_timeout(&mut self, connect_timeout: StdDuration) {
+        self.connect_timeout = connect_timeout;
+    }
+
+    /// Enables or disables logging of every outgoing gateway command at the debug level. The
+    /// setting is kept across reconnects. See [`WsClient::set_log_outgoing`].
+    pub fn set_log_outgoing(&mut self, enabled: bool) {
+        self.client.set_log_outgoing(enabled);
+    }
+
     /// Retrieves the current presence of the shard.
     #[inline]
     pub fn presence(&self) -> &PresenceData {
@@ -393,7 +431,12 @@ impl Shard {
                 return Err(Error::Gateway(GatewayError::InvalidGatewayIntents));
             },
             Some(close_codes::DISALLOWED_GATEWAY_INTENTS) => {
-                error!("[{:?}] Disallowed gateway intents have been provided.", self.shard_info);
+                error!(
+                    "[{:?}] Disallowed gateway intents have been provided. Make sure the \
+                     privileged intents {:?} are enabled in the Developer Portal.",
+                    self.shard_info,
+                    self.intents.privileged_subset(),
+                );
 
                 return Err(Error::Gateway(GatewayError::DisallowedGatewayIntents));
             },
@@ -469,7 +512,16 @@ impl Shard {
                 }))
             },
             &Ok(GatewayEvent::InvalidateSession(resumable)) => {
-                info!("[{:?}] Received session invalidation", self.shard_info);
+                info!(
+                    "[{:?}] Received session invalidation; resumable: {}",
+                    self.shard_info, resumable
+                );
+
+                // A session that can't be resumed must not be used for a RESUME again.
+                if !resumable {
+                    self.session_id = None;
+                    self.seq = 0;
+                }
 
                 Ok(Some(if resumable {
                     ShardAction::Reconnect(ReconnectType::Resume)
@@ -704,7 +756,8 @@ impl Shard {
         self.stage = ConnectionStage::Connecting;
         self.started = Instant::now();
         let url = &self.ws_url.lock().await.clone();
-        let client = connect(url).await?;
+        let mut client = connect(url, self.connect_timeout).await?;
+        client.set_log_outgoing(self.client.is_logging_outgoing());
         self.stage = ConnectionStage::Handshake;
 
         Ok(client)
@@ -752,7 +805,7 @@ impl Shard {
     }
 }
 
-async fn connect(base_url: &str) -> Result<WsClient> {
+async fn connect(base_url: &str, connect_timeout: StdDuration) -> Result<WsClient> {
     let url =
         Url::parse(&format!("{base_url}?v={}", constants::GATEWAY_VERSION)).map_err(|why| {
             warn!("Error building gateway URL with base `{}`: {:?}", base_url, why);
@@ -760,5 +813,47 @@ async fn connect(base_url: &str) -> Result<WsClient> {
             Error::Gateway(GatewayError::BuildingUrl)
         })?;
 
-    WsClient::connect(url).await
+    WsClient::connect(url, connect_timeout).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::id::ShardId;
+
+    async fn shard() -> Shard {
+        Shard {
+            client: WsClient::unconnected().await,
+            presence: PresenceData::default(),
+            last_heartbeat_sent: None,
+            last_heartbeat_ack: None,
+            heartbeat_interval: None,
+            application_id_callback: None,
+            last_heartbeat_acknowledged: true,
+            seq: 5,
+            session_id: Some("session".into()),
+            shard_info: ShardInfo::new(ShardId(0), 1),
+            stage: ConnectionStage::Connected,
+            started: Instant::now(),
+            token: String::new(),
+            ws_url: Arc::new(Mutex::new(String::new())),
+            connect_timeout: CONNECT_TIMEOUT,
+            intents: GatewayIntents::empty(),
+        }
+    }
+
+    #[tokio::test]
+    async fn invalidate_session() {
+        let mut shard = shard().await;
+        let action = shard.handle_event(&Ok(GatewayEvent::InvalidateSession(true))).unwrap();
+        assert!(matches!(action, Some(ShardAction::Reconnect(ReconnectType::Resume))));
+        assert_eq!(shard.session_id(), Some(&"session".to_string()));
+        assert_eq!(shard.seq(), 5);
+
+        let action = shard.handle_event(&Ok(GatewayEvent::InvalidateSession(false))).unwrap();
+        assert!(matches!(action, Some(ShardAction::Reconnect(ReconnectType::Reidentify))));
+        assert_eq!(shard.session_id(), None);
+        assert_eq!(shard.seq(), 0);
+        assert!(matches!(shard.reconnection_type(), ReconnectType::Reidentify));
+    }
 }
@@ -1,7 +1,7 @@
 use std::env::consts;
 #[cfg(feature = "client")]
 use std::io::Read;
-use std::time::SystemTime;
+use std::time::{Instant, SystemTime};
 
 #[cfg(feature = "client")]
 use flate2::read::ZlibDecoder;
@@ -9,7 +9,6 @@ use futures::SinkExt;
 #[cfg(feature = "client")]
 use futures::StreamExt;
 use tokio::net::TcpStream;
-#[cfg(feature = "client")]
 use tokio::time::{timeout, Duration};
 #[cfg(feature = "client")]
 use tokio_tungstenite::tungstenite::protocol::CloseFrame;
@@ -25,16 +24,16 @@ use url::Url;
 
 use super::{ActivityData, ChunkGuildFilter, PresenceData};
 use crate::constants::{self, Opcode};
-#[cfg(feature = "client")]
 use crate::gateway::GatewayError;
 #[cfg(feature = "client")]
 use crate::json::from_str;
-use crate::json::to_string;
+use crate::json::{to_string, to_value, Value, NULL};
+#[cfg(feature = "simd_json")]
+use crate::json::{ValueAsContainer, ValueAsMutContainer};
 #[cfg(feature = "client")]
-use crate::model::event::GatewayEvent;
+use crate::model::event::{Event, GatewayEvent};
 use crate::model::gateway::{GatewayIntents, ShardInfo};
 use crate::model::id::{GuildId, UserId};
-#[cfg(feature = "client")]
 use crate::Error;
 use crate::Result;
 
@@ -93,35 +92,97 @@ struct WebSocketMessage<'a> {
     d: WebSocketMessageData<'a>,
 }
 
-pub struct WsClient(WebSocketStream<MaybeTlsStream<TcpStream>>);
+pub struct WsClient {
+    stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    heartbeat_interval: Option<std::time::Duration>,
+    last_pong: Option<Instant>,
+    resumed: bool,
+    resume_acknowledged: bool,
+    log_outgoing: bool,
+}
 
 #[cfg(feature = "client")]
 const TIMEOUT: Duration = Duration::from_millis(500);
+/// The default amount of time to wait for the WebSocket connection to be established.
+pub(crate) const CONNECT_TIMEOUT: Duration = Duration::from_secs(20);
 #[cfg(feature = "client")]
 const DECOMPRESSION_MULTIPLIER: usize = 3;
+/// The maximum number of user Ids Discord accepts in a single member chunk request.
+const CHUNK_GUILD_USER_IDS_LIMIT: usize = 100;
+/// The maximum length of a member chunk request nonce, in bytes.
+const CHUNK_GUILD_NONCE_LIMIT: usize = 32;
 
 impl WsClient {
-    pub(crate) async fn connect(url: Url) -> Result<Self> {
+    /// Opens a new connection to the gateway, failing with [`GatewayError::ConnectionTimeout`] if
+    /// it isn't established within `connect_timeout`.
+    pub(crate) async fn connect(url: Url, connect_timeout: Duration) -> Result<Self> {
         let config = WebSocketConfig {
             max_message_size: None,
             max_frame_size: None,
             ..Default::default()
         };
-        let (stream, _) = connect_async_with_config(url, Some(config), false).await?;
+        let connection = connect_async_with_config(url, Some(config), false);
+        let (stream, _) = timeout(connect_timeout, connection)
+            .await
+            .map_err(|_| Error::Gateway(GatewayError::ConnectionTimeout))??;
+
+        Ok(Self {
+            stream,
+            heartbeat_interval: None,
+            last_pong: None,
+            resumed: false,
+            resume_acknowledged: false,
+            log_outgoing: false,
+        })
+    }
+
+    /// Returns the heartbeat interval dictated by Discord, if a Hello has been received over this
+    /// connection.
+    #[must_use]
+    pub fn heartbeat_interval(&self) -> Option<std::time::Duration> {
+        self.heartbeat_interval
+    }
 
-        Ok(Self(stream))
+    /// Returns the instant of when the last WebSocket-level pong was received over this
+    /// connection.
+    ///
+    /// This is independent of Discord's heartbeat acknowledgements, and can be used to monitor
+    /// the liveness of the underlying connection.
+    #[must_use]
+    pub fn last_pong(&self) -> Option<Instant> {
+        self.last_pong
+    }
+
+    /// Returns whether the session was resumed over this connection, rather than freshly
+    /// identified.
+    ///
+    /// When resuming, Discord replays the missed events instead of sending a full `READY`, so the
+    /// cache is only as complete as it was before the disconnect.
+    #[must_use]
+    pub fn resumed(&self) -> bool {
+        self.resumed
+    }
+
+    /// Returns whether Discord has confirmed the resume with a `RESUMED` dispatch, meaning that
+    /// all missed events have been replayed.
+    #[must_use]
+    pub fn resume_acknowledged(&self) -> bool {
+        self.resume_acknowledged
     }
 
     #[cfg(feature = "client")]
     pub(crate) async fn recv_json(&mut self) -> Result<Option<GatewayEvent>> {
-        let message = match timeout(TIMEOUT, self.0.next()).await {
+        let message = match timeout(TIMEOUT, self.stream.next()).await {
             Ok(Some(Ok(msg))) => msg,
             Ok(Some(Err(e))) => return Err(e.into()),
             Ok(None) | Err(_) => return Ok(None),
         };
 
-        let value = match message {
+        let value: GatewayEvent = match message {
             Message::Binary(bytes) => {
+                // Only payload compression is requested when identifying, so each binary message
+                // is a complete zlib stream and a fresh decoder is used every time. There is no
+                // inflater state that could carry over between connections.
                 let mut decompressed =
                     String::with_capacity(bytes.len() * DECOMPRESSION_MULTIPLIER);
 
@@ -132,13 +193,17 @@ impl WsClient {
                     why
                 })?;
 
-                from_str(&decompressed).map_err(|why| {
+                // The decompressed string is not needed afterwards, so hand it over to avoid the
+                // copy `from_str` makes of borrowed input when `simd_json` is enabled.
+                from_str(decompressed).map_err(|why| {
                     warn!("Err deserializing bytes: {why:?}");
                     debug!("Failing bytes: {bytes:?}");
 
                     why
                 })?
             },
+            // Text frames are parsed from the borrowed payload, which `serde_json` reads without
+            // copying. It is kept around for logging if deserialization fails.
             Message::Text(payload) => from_str(&payload).map_err(|why| {
                 warn!("Err deserializing text: {why:?}; text: {payload}");
 
@@ -147,36 +212,96 @@ impl WsClient {
             Message::Close(Some(frame)) => {
                 return Err(Error::Gateway(GatewayError::Closed(Some(frame))));
             },
+            Message::Ping(_) => {
+                // Tungstenite queues the reply to a ping when reading it, but only writes it out
+                // on the next send or flush, so flush it here rather than waiting.
+                trace!("Received ping; flushing pong");
+                self.stream.flush().await?;
+                return Ok(None);
+            },
+            Message::Pong(_) => {
+                trace!("Received pong");
+                self.last_pong = Some(Instant::now());
+                return Ok(None);
+            },
             _ => return Ok(None),
         };
 
+        match &value {
+            GatewayEvent::Hello(interval) => {
+                self.heartbeat_interval = Some(std::time::Duration::from_millis(*interval));
+            },
+            GatewayEvent::Dispatch(_, Event::Resumed(_)) => {
+                debug!("Received resumed dispatch; resume acknowledged");
+                self.resume_acknowledged = true;
+            },
+            _ => {},
+        }
+
         Ok(Some(value))
     }
 
+    /// Enables or disables logging of every outgoing gateway command at the debug level.
+    ///
+    /// The opcode and payload of each command are logged, with the token of IDENTIFY and RESUME
+    /// payloads redacted. Disabled by default.
+    pub fn set_log_outgoing(&mut self, enabled: bool) {
+        self.log_outgoing = enabled;
+    }
+
+    /// Whether outgoing gateway commands are logged. See [`Self::set_log_outgoing`].
+    #[must_use]
+    pub fn is_logging_outgoing(&self) -> bool {
+        self.log_outgoing
+    }
+
     pub(crate) async fn send_json(&mut self, value: &impl serde::Serialize) -> Result<()> {
+        if self.log_outgoing {
+            log_outgoing(value);
+        }
+
         let message = to_string(value).map(Message::Text)?;
 
-        self.0.send(message).await?;
+        self.stream.send(message).await?;
+        Ok(())
+    }
+
+    /// Queues a gateway command without flushing it, so that a burst of commands can be written
+    /// to the connection at once with [`Self::flush`].
+    pub(crate) async fn send_json_buffered(&mut self, value: &impl serde::Serialize) -> Result<()> {
+        if self.log_outgoing {
+            log_outgoing(value);
+        }
+
+        let message = to_string(value).map(Message::Text)?;
+
+        self.stream.feed(message).await?;
+        Ok(())
+    }
+
+    /// Flushes all gateway commands queued with [`Self::send_json_buffered`].
+    pub(crate) async fn flush(&mut self) -> Result<()> {
+        self.stream.flush().await?;
         Ok(())
     }
 
     /// Delegate to `StreamExt::next`
     #[cfg(feature = "client")]
     pub(crate) async fn next(&mut self) -> Option<std::result::Result<Message, WsError>> {
-        self.0.next().await
+        self.stream.next().await
     }
 
     /// Delegate to `SinkExt::send`
     #[cfg(feature = "client")]
     pub(crate) async fn send(&mut self, message: Message) -> Result<()> {
-        self.0.send(message).await?;
+        self.stream.send(message).await?;
         Ok(())
     }
 
     /// Delegate to `WebSocketStream::close`
     #[cfg(feature = "client")]
     pub(crate) async fn close(&mut self, msg: Option<CloseFrame<'_>>) -> Result<()> {
-        self.0.close(msg).await?;
+        self.stream.close(msg).await?;
         Ok(())
     }
 
@@ -189,6 +314,20 @@ impl WsClient {
         presences: bool,
         filter: ChunkGuildFilter,
         nonce: Option<&str>,
+    ) -> Result<()> {
+        self.feed_chunk_guild(guild_id, shard_info, limit, presences, filter, nonce).await?;
+        self.flush().await
+    }
+
+    /// Queues a member chunk request without flushing it. See [`Self::send_json_buffered`].
+    async fn feed_chunk_guild(
+        &mut self,
+        guild_id: GuildId,
+        shard_info: &ShardInfo,
+        limit: Option<u16>,
+        presences: bool,
+        filter: ChunkGuildFilter,
+        nonce: Option<&str>,
     ) -> Result<()> {
         debug!("[{:?}] Requesting member chunks", shard_info);
 
@@ -198,7 +337,7 @@ impl WsClient {
             ChunkGuildFilter::UserIds(user_ids) => (None, Some(user_ids)),
         };
 
-        self.send_json(&WebSocketMessage {
+        self.send_json_buffered(&WebSocketMessage {
             op: Opcode::RequestGuildMembers,
             d: WebSocketMessageData::ChunkGuild(ChunkGuildMessage {
                 guild_id,
@@ -212,6 +351,46 @@ impl WsClient {
         .await
     }
 
+    /// Requests the members with the given Ids, splitting them into as many chunk requests as
+    /// needed to stay within Discord's limit of 100 user Ids per request.
+    ///
+    /// If a `nonce` is given, each request is sent with the nonce suffixed by the index of its
+    /// batch, e.g. `nonce:0`, `nonce:1`, so the resulting chunk events can be correlated. As
+    /// Discord limits nonces to 32 bytes, the given nonce must leave room for the suffix, e.g. be
+    /// at most 30 bytes long for up to 1000 Ids.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ExceededLimit`] if a suffixed nonce would be longer than 32 bytes, in
+    /// which case no request is sent.
+    pub async fn chunk_members_by_ids(
+        &mut self,
+        guild_id: GuildId,
+        shard_info: &ShardInfo,
+        ids: &[UserId],
+        presences: bool,
+        nonce: Option<&str>,
+    ) -> Result<()> {
+        if let Some(nonce) = nonce {
+            let last_index = ids.len().saturating_sub(1) / CHUNK_GUILD_USER_IDS_LIMIT;
+            let last_nonce = format!("{nonce}:{last_index}");
+            if last_nonce.len() > CHUNK_GUILD_NONCE_LIMIT {
+                return Err(Error::ExceededLimit(last_nonce, CHUNK_GUILD_NONCE_LIMIT as u32));
+            }
+        }
+
+        for (index, batch) in ids.chunks(CHUNK_GUILD_USER_IDS_LIMIT).enumerate() {
+            let nonce = nonce.map(|nonce| format!("{nonce}:{index}"));
+            let filter = ChunkGuildFilter::UserIds(batch.to_vec());
+
+            self.feed_chunk_guild(guild_id, shard_info, None, presences, filter, nonce.as_deref())
+                .await?;
+        }
+
+        // Flush all requests at once, instead of once per batch.
+        self.flush().await
+    }
+
     #[instrument(skip(self))]
     pub async fn send_heartbeat(&mut self, shard_info: &ShardInfo, seq: Option<u64>) -> Result<()> {
         trace!("[{:?}] Sending heartbeat d: {:?}", shard_info, seq);
@@ -235,6 +414,12 @@ impl WsClient {
         let now = SystemTime::now();
 
         debug!("[{:?}] Identifying", shard);
+        self.resumed = false;
+        self.resume_acknowledged = false;
+        let privileged = intents.privileged_subset();
+        if !privileged.is_empty() {
+            debug!("[{:?}] Requesting privileged intents {:?}", shard, privileged);
+        }
 
         let msg = WebSocketMessage {
             op: Opcode::Identify,
@@ -293,6 +478,8 @@ impl WsClient {
         token: &str,
     ) -> Result<()> {
         debug!("[{:?}] Sending resume; seq: {}", shard_info, seq);
+        self.resumed = true;
+        self.resume_acknowledged = false;
 
         self.send_json(&WebSocketMessage {
             op: Opcode::Resume,
@@ -305,3 +492,48 @@ impl WsClient {
         .await
     }
 }
+
+/// Logs an outgoing gateway command, never including the token in full.
+fn log_outgoing(value: &impl serde::Serialize) {
+    let Ok(mut payload) = to_value(value) else {
+        return;
+    };
+
+    let token = payload
+        .as_object_mut()
+        .and_then(|payload| payload.get_mut("d"))
+        .and_then(|d| d.as_object_mut())
+        .and_then(|d| d.get_mut("token"));
+    if let Some(token) = token {
+        *token = Value::from("[redacted]");
+    }
+
+    let op = payload.as_object().and_then(|payload| payload.get("op")).unwrap_or(&NULL);
+    debug!("Sending gateway command; op: {}, payload: {}", op, payload);
+}
+
+#[cfg(test)]
+impl WsClient {
+    /// Wraps a local TCP connection without performing a WebSocket handshake, for tests that need
+    /// a client but never send over it.
+    pub(crate) async fn unconnected() -> Self {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let stream = std::net::TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        stream.set_nonblocking(true).unwrap();
+        let stream = MaybeTlsStream::Plain(TcpStream::from_std(stream).unwrap());
+
+        Self {
+            stream: WebSocketStream::from_raw_socket(
+                stream,
+                tokio_tungstenite::tungstenite::protocol::Role::Client,
+                None,
+            )
+            .await,
+            heartbeat_interval: None,
+            last_pong: None,
+            resumed: false,
+            resume_acknowledged: false,
+            log_outgoing: false,
+        }
+    }
+}
@@ -302,6 +302,18 @@ impl Http {
     ) -> Result<()> {
         let delete_message_seconds = u32::from(delete_message_days) * 86400;
 
+        self.ban_user_with_message_deletion(guild_id, user_id, delete_message_seconds, reason).await
+    }
+
+    /// Bans a [`User`] from a [`Guild`], removing their messages sent in the last
+    /// `delete_message_seconds` seconds.
+    pub async fn ban_user_with_message_deletion(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+        delete_message_seconds: u32,
+        reason: Option<&str>,
+    ) -> Result<()> {
         self.wind(204, Request {
             body: None,
             multipart: None,
@@ -661,7 +673,7 @@ impl Http {
     /// [documentation on this endpoint]:
     /// https://discord.com/developers/docs/resources/guild#create-guild
     /// [whitelist]: https://discord.com/developers/docs/resources/guild#create-guild
-    pub async fn create_guild(&self, map: &Value) -> Result<PartialGuild> {
+    pub async fn create_guild(&self, map: &impl serde::Serialize) -> Result<PartialGuild> {
         self.fire(Request {
             body: Some(to_vec(map)?),
             multipart: None,
@@ -728,6 +740,30 @@ impl Http {
         .await
     }
 
+    /// Creates a template from a guild's current state.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn create_guild_template(
+        &self,
+        guild_id: GuildId,
+        map: &impl serde::Serialize,
+    ) -> Result<GuildTemplate> {
+        let body = to_vec(map)?;
+        self.fire(Request {
+            body: Some(body),
+            multipart: None,
+            headers: None,
+            method: LightMethod::Post,
+            route: Route::GuildTemplates {
+                guild_id,
+            },
+            params: None,
+        })
+        .await
+    }
+
     /// Creates a response to an [`Interaction`] from the gateway.
     ///
     /// Refer to Discord's [docs] for the object it takes.
@@ -911,6 +947,30 @@ impl Http {
         .await
     }
 
+    /// Creates a soundboard sound in a guild.
+    ///
+    /// **Note**: Requires the [Create Guild Expressions] permission.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    pub async fn create_soundboard_sound(
+        &self,
+        guild_id: GuildId,
+        map: &impl serde::Serialize,
+        audit_log_reason: Option<&str>,
+    ) -> Result<SoundboardSound> {
+        self.fire(Request {
+            body: Some(to_vec(map)?),
+            multipart: None,
+            headers: audit_log_reason.map(reason_into_header),
+            method: LightMethod::Post,
+            route: Route::GuildSoundboardSounds {
+                guild_id,
+            },
+            params: None,
+        })
+        .await
+    }
+
     /// Creates a sticker.
     ///
     /// **Note**: Requires the [Create Guild Expressions] permission.
@@ -1197,6 +1257,30 @@ impl Http {
         .await
     }
 
+    /// Deletes a guild template, returning the deleted template.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn delete_guild_template(
+        &self,
+        guild_id: GuildId,
+        code: &str,
+    ) -> Result<GuildTemplate> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Delete,
+            route: Route::GuildTemplate {
+                guild_id,
+                code,
+            },
+            params: None,
+        })
+        .await
+    }
+
     /// Deletes an invite by code.
     pub async fn delete_invite(
         &self,
@@ -1448,6 +1532,29 @@ impl Http {
         .await
     }
 
+    /// Deletes a soundboard sound from a guild.
+    ///
+    /// See [`GuildId::delete_soundboard_sound`] for permissions requirements.
+    pub async fn delete_soundboard_sound(
+        &self,
+        guild_id: GuildId,
+        sound_id: SoundId,
+        audit_log_reason: Option<&str>,
+    ) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            multipart: None,
+            headers: audit_log_reason.map(reason_into_header),
+            method: LightMethod::Delete,
+            route: Route::GuildSoundboardSound {
+                guild_id,
+                sound_id,
+            },
+            params: None,
+        })
+        .await
+    }
+
     /// Deletes a sticker from a server.
     ///
     /// See [`GuildId::delete_sticker`] for permissions requirements.
@@ -1896,6 +2003,28 @@ impl Http {
         .await
     }
 
+    /// Edits a guild's discovery metadata.
+    pub async fn edit_guild_discovery_metadata(
+        &self,
+        guild_id: GuildId,
+        map: &impl serde::Serialize,
+        audit_log_reason: Option<&str>,
+    ) -> Result<GuildDiscoveryMetadata> {
+        let body = to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(body),
+            multipart: None,
+            headers: audit_log_reason.map(reason_into_header),
+            method: LightMethod::Patch,
+            route: Route::GuildDiscoveryMetadata {
+                guild_id,
+            },
+            params: None,
+        })
+        .await
+    }
+
     /// Edits a guild welcome screen.
     pub async fn edit_guild_welcome_screen(
         &self,
@@ -1918,6 +2047,28 @@ impl Http {
         .await
     }
 
+    /// Edits a guild's onboarding flow.
+    pub async fn edit_guild_onboarding(
+        &self,
+        guild_id: GuildId,
+        map: &impl serde::Serialize,
+        audit_log_reason: Option<&str>,
+    ) -> Result<GuildOnboarding> {
+        let body = to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(body),
+            multipart: None,
+            headers: audit_log_reason.map(reason_into_header),
+            method: LightMethod::Put,
+            route: Route::GuildOnboarding {
+                guild_id,
+            },
+            params: None,
+        })
+        .await
+    }
+
     /// Does specific actions to a member.
     pub async fn edit_member(
         &self,
@@ -2186,7 +2337,20 @@ impl Http {
             "id": role_id,
             "position": position,
         }]);
-        let body = to_vec(&map)?;
+
+        self.edit_role_positions(guild_id, &map, audit_log_reason).await
+    }
+
+    /// Changes the positions of multiple roles in a guild at once.
+    ///
+    /// The `value` must be an array of objects containing the `id` and `position` of each role.
+    pub async fn edit_role_positions(
+        &self,
+        guild_id: GuildId,
+        value: &Value,
+        audit_log_reason: Option<&str>,
+    ) -> Result<Vec<Role>> {
+        let body = to_vec(value)?;
 
         let mut value: Value = self
             .fire(Request {
@@ -2239,6 +2403,30 @@ impl Http {
         .await
     }
 
+    /// Changes a soundboard sound in a guild.
+    ///
+    /// See [`GuildId::edit_soundboard_sound`] for permissions requirements.
+    pub async fn edit_soundboard_sound(
+        &self,
+        guild_id: GuildId,
+        sound_id: SoundId,
+        map: &impl serde::Serialize,
+        audit_log_reason: Option<&str>,
+    ) -> Result<SoundboardSound> {
+        self.fire(Request {
+            body: Some(to_vec(map)?),
+            multipart: None,
+            headers: audit_log_reason.map(reason_into_header),
+            method: LightMethod::Patch,
+            route: Route::GuildSoundboardSound {
+                guild_id,
+                sound_id,
+            },
+            params: None,
+        })
+        .await
+    }
+
     /// Changes a sticker in a guild.
     ///
     /// See [`GuildId::edit_sticker`] for permissions requirements.
@@ -3643,6 +3831,24 @@ impl Http {
         .await
     }
 
+    /// Gets a guild's discovery metadata.
+    pub async fn get_guild_discovery_metadata(
+        &self,
+        guild_id: GuildId,
+    ) -> Result<GuildDiscoveryMetadata> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Get,
+            route: Route::GuildDiscoveryMetadata {
+                guild_id,
+            },
+            params: None,
+        })
+        .await
+    }
+
     /// Gets a guild welcome screen information.
     pub async fn get_guild_welcome_screen(&self, guild_id: GuildId) -> Result<GuildWelcomeScreen> {
         self.fire(Request {
@@ -3658,6 +3864,21 @@ impl Http {
         .await
     }
 
+    /// Gets a guild's onboarding flow.
+    pub async fn get_guild_onboarding(&self, guild_id: GuildId) -> Result<GuildOnboarding> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Get,
+            route: Route::GuildOnboarding {
+                guild_id,
+            },
+            params: None,
+        })
+        .await
+    }
+
     /// Gets integrations that a guild has.
     pub async fn get_guild_integrations(&self, guild_id: GuildId) -> Result<Vec<Integration>> {
         self.fire(Request {
@@ -3688,14 +3909,16 @@ impl Http {
         .await
     }
 
-    /// Gets a guild's vanity URL if it has one.
+    /// Gets a guild's vanity URL if it has one, or an empty string otherwise.
+    ///
+    /// See [`Self::get_guild_vanity_invite`] to also get the number of uses.
     pub async fn get_guild_vanity_url(&self, guild_id: GuildId) -> Result<String> {
-        #[derive(Deserialize)]
-        struct GuildVanityUrl {
-            code: String,
-        }
+        self.get_guild_vanity_invite(guild_id).await.map(|v| v.code.unwrap_or_default())
+    }
 
-        self.fire::<GuildVanityUrl>(Request {
+    /// Gets a guild's vanity invite code along with its number of uses.
+    pub async fn get_guild_vanity_invite(&self, guild_id: GuildId) -> Result<VanityInviteInfo> {
+        self.fire(Request {
             body: None,
             multipart: None,
             headers: None,
@@ -3706,7 +3929,6 @@ impl Http {
             params: None,
         })
         .await
-        .map(|x| x.code)
     }
 
     /// Gets the members of a guild. Optionally pass a `limit` and the Id of the user to offset the
@@ -3933,6 +4155,52 @@ impl Http {
         .await
     }
 
+    /// Retrieves a list of soundboard sounds in a [`Guild`].
+    pub async fn get_guild_soundboard_sounds(
+        &self,
+        guild_id: GuildId,
+    ) -> Result<Vec<SoundboardSound>> {
+        #[derive(Deserialize)]
+        struct SoundboardSounds {
+            items: Vec<SoundboardSound>,
+        }
+
+        let sounds: SoundboardSounds = self
+            .fire(Request {
+                body: None,
+                multipart: None,
+                headers: None,
+                method: LightMethod::Get,
+                route: Route::GuildSoundboardSounds {
+                    guild_id,
+                },
+                params: None,
+            })
+            .await?;
+
+        Ok(sounds.items)
+    }
+
+    /// Retrieves a single soundboard sound in a [`Guild`].
+    pub async fn get_guild_soundboard_sound(
+        &self,
+        guild_id: GuildId,
+        sound_id: SoundId,
+    ) -> Result<SoundboardSound> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Get,
+            route: Route::GuildSoundboardSound {
+                guild_id,
+                sound_id,
+            },
+            params: None,
+        })
+        .await
+    }
+
     /// Retrieves a list of stickers in a [`Guild`].
     pub async fn get_guild_stickers(&self, guild_id: GuildId) -> Result<Vec<Sticker>> {
         let mut value: Value = self
@@ -3986,6 +4254,25 @@ impl Http {
         from_value(value).map_err(From::from)
     }
 
+    /// Gets all templates of a guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn get_guild_templates(&self, guild_id: GuildId) -> Result<Vec<GuildTemplate>> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Get,
+            route: Route::GuildTemplates {
+                guild_id,
+            },
+            params: None,
+        })
+        .await
+    }
+
     /// Retrieves the webhooks for the given [guild][`Guild`]'s Id.
     ///
     /// This method requires authentication.
@@ -4759,6 +5046,31 @@ impl Http {
         .await
     }
 
+    /// Starts removing some members from a guild based on the last time they've been online, with
+    /// additional options such as roles to include and whether to compute the pruned count.
+    ///
+    /// See [`GuildId::start_prune_with_options`] for the accepted fields.
+    ///
+    /// [`GuildId::start_prune_with_options`]: crate::model::id::GuildId::start_prune_with_options
+    pub async fn start_guild_prune_with_options(
+        &self,
+        guild_id: GuildId,
+        map: &impl serde::Serialize,
+        audit_log_reason: Option<&str>,
+    ) -> Result<GuildPrune> {
+        self.fire(Request {
+            body: Some(to_vec(map)?),
+            multipart: None,
+            headers: audit_log_reason.map(reason_into_header),
+            method: LightMethod::Post,
+            route: Route::GuildPrune {
+                guild_id,
+            },
+            params: None,
+        })
+        .await
+    }
+
     /// Starts syncing an integration with a guild.
     pub async fn start_integration_sync(
         &self,
@@ -4819,6 +5131,30 @@ impl Http {
         Typing::start(Arc::clone(self), channel_id)
     }
 
+    /// Syncs a guild template to the guild's current state.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn sync_guild_template(
+        &self,
+        guild_id: GuildId,
+        code: &str,
+    ) -> Result<GuildTemplate> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            method: LightMethod::Put,
+            route: Route::GuildTemplate {
+                guild_id,
+                code,
+            },
+            params: None,
+        })
+        .await
+    }
+
     /// Unpins a message from a channel.
     pub async fn unpin_message(
         &self,
@@ -4974,3 +5310,27 @@ impl AsRef<Http> for Http {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_token, Http};
+
+    #[test]
+    fn parse_token_prefixes() {
+        assert_eq!(parse_token("Bot X.Y.Z"), "Bot X.Y.Z");
+        assert_eq!(parse_token("Bearer X.Y.Z"), "Bearer X.Y.Z");
+        assert_eq!(parse_token("X.Y.Z"), "Bot X.Y.Z");
+    }
+
+    #[test]
+    fn parse_token_trims_whitespace() {
+        assert_eq!(parse_token("  X.Y.Z\n"), "Bot X.Y.Z");
+        assert_eq!(parse_token("\tBearer X.Y.Z "), "Bearer X.Y.Z");
+    }
+
+    #[test]
+    fn token_has_authorization_scheme() {
+        assert_eq!(Http::new(" X.Y.Z ").token(), "Bot X.Y.Z");
+        assert_eq!(Http::new("Bearer X.Y.Z").token(), "Bearer X.Y.Z");
+    }
+}
@@ -242,6 +242,10 @@ routes! ('a, {
     api!("/guilds/{}/widget", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
+    GuildDiscoveryMetadata { guild_id: GuildId },
+    api!("/guilds/{}/discovery-metadata", guild_id),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
     GuildPreview { guild_id: GuildId },
     api!("/guilds/{}/preview", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
@@ -294,6 +298,10 @@ routes! ('a, {
     api!("/guilds/{}/mfa", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
+    GuildOnboarding { guild_id: GuildId },
+    api!("/guilds/{}/onboarding", guild_id),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
     GuildPrune { guild_id: GuildId },
     api!("/guilds/{}/prune", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
@@ -322,6 +330,14 @@ routes! ('a, {
     api!("/guilds/{}/scheduled-events/{}/users", guild_id, event_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
+    GuildSoundboardSound { guild_id: GuildId, sound_id: SoundId },
+    api!("/guilds/{}/soundboard-sounds/{}", guild_id, sound_id),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
+    GuildSoundboardSounds { guild_id: GuildId },
+    api!("/guilds/{}/soundboard-sounds", guild_id),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
     GuildSticker { guild_id: GuildId, sticker_id: StickerId },
     api!("/guilds/{}/stickers/{}", guild_id, sticker_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
@@ -330,6 +346,14 @@ routes! ('a, {
     api!("/guilds/{}/stickers", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
+    GuildTemplate { guild_id: GuildId, code: &'a str },
+    api!("/guilds/{}/templates/{}", guild_id, code),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
+    GuildTemplates { guild_id: GuildId },
+    api!("/guilds/{}/templates", guild_id),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
     GuildVanityUrl { guild_id: GuildId },
     api!("/guilds/{}/vanity-url", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
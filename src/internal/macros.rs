@@ -10,6 +10,16 @@ macro_rules! cdn {
     };
 }
 
+#[cfg(feature = "model")]
+macro_rules! media {
+    ($e:expr) => {
+        concat!("https://media.discordapp.net", $e)
+    };
+    ($e:expr, $($rest:tt)*) => {
+        format!(media!($e), $($rest)*)
+    };
+}
+
 #[cfg(feature = "http")]
 macro_rules! api {
     ($e:expr) => {
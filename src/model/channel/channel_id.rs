@@ -555,6 +555,24 @@ impl ChannelId {
         http.as_ref().pin_message(self, message_id.into(), None).await
     }
 
+    /// Pins a [`Message`] to the channel, with a reason that will appear in the audit log.
+    ///
+    /// Refer to [`Self::pin`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the channel has too
+    /// many pinned messages.
+    #[inline]
+    pub async fn pin_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+        reason: &str,
+    ) -> Result<()> {
+        http.as_ref().pin_message(self, message_id.into(), Some(reason)).await
+    }
+
     /// Crossposts a [`Message`].
     ///
     /// Requires either to be the message author or to have manage [Manage Messages] permissions on
@@ -803,6 +821,24 @@ impl ChannelId {
         http.as_ref().unpin_message(self, message_id.into(), None).await
     }
 
+    /// Unpins a [`Message`] in the channel given by its Id, with a reason that will appear in the
+    /// audit log.
+    ///
+    /// Refer to [`Self::unpin`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    #[inline]
+    pub async fn unpin_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+        reason: &str,
+    ) -> Result<()> {
+        http.as_ref().unpin_message(self, message_id.into(), Some(reason)).await
+    }
+
     /// Retrieves the channel's webhooks.
     ///
     /// **Note**: Requires the [Manage Webhooks] permission.
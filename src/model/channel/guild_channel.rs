@@ -212,6 +212,38 @@ impl GuildChannel {
         )
     }
 
+    /// Whether invites can be created for this channel. This excludes categories and threads.
+    #[must_use]
+    pub fn is_invitable(&self) -> bool {
+        matches!(
+            self.kind,
+            ChannelType::Text
+                | ChannelType::News
+                | ChannelType::Voice
+                | ChannelType::Stage
+                | ChannelType::Forum
+        )
+    }
+
+    /// Resolves the [`Self::default_reaction_emoji`] of a forum channel into a [`ReactionType`]
+    /// that can be used to react to messages.
+    ///
+    /// Custom emojis are looked up in the given guild's [`Guild::emojis`] to fill in their name
+    /// and whether they're animated. If the emoji can't be found there, a placeholder name is
+    /// used, which Discord accepts as long as the Id is valid.
+    #[must_use]
+    pub fn default_reaction(&self, guild: &Guild) -> Option<ReactionType> {
+        let reaction = match self.default_reaction_emoji.as_ref()? {
+            ForumEmoji::Id(id) => match guild.emojis.get(id) {
+                Some(emoji) => ReactionType::from(emoji.clone()),
+                None => ReactionType::from(*id),
+            },
+            ForumEmoji::Name(name) => ReactionType::Unicode(name.clone()),
+        };
+
+        Some(reaction)
+    }
+
     /// Broadcasts to the channel that the current user is typing.
     ///
     /// For bots, this is a good indicator for long-running commands.
@@ -220,11 +252,17 @@ impl GuildChannel {
     ///
     /// # Errors
     ///
+    /// Returns [`ModelError::InvalidChannelType`] if the channel is not text-based.
+    ///
     /// Returns [`Error::Http`] if the current user does not have the required permissions.
     ///
     /// [Send Messages]: Permissions::SEND_MESSAGES
     #[inline]
     pub async fn broadcast_typing(&self, http: impl AsRef<Http>) -> Result<()> {
+        if !self.is_text_based() {
+            return Err(Error::Model(ModelError::InvalidChannelType));
+        }
+
         self.id.broadcast_typing(http).await
     }
 
@@ -236,24 +274,38 @@ impl GuildChannel {
     ///
     /// Create an invite that can only be used 5 times:
     ///
-    /// ```rust,ignore
-    /// let builder = CreateBuilder::default().max_uses(5);
-    /// let invite = channel.create_invite(&context, builder).await;
+    /// ```rust,no_run
+    /// # use serenity::builder::CreateInvite;
+    /// # use serenity::http::Http;
+    /// # use serenity::model::channel::GuildChannel;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http: Http = unimplemented!();
+    /// # let channel: GuildChannel = unimplemented!();
+    /// let builder = CreateInvite::new().max_uses(5);
+    /// let invite = channel.create_invite(&http, builder).await?;
+    /// println!("Invite created: {}", invite.url());
+    /// # Ok(())
+    /// # }
     /// ```
     ///
     /// # Errors
     ///
+    /// Returns [`ModelError::InvalidChannelType`] if the channel cannot have invites, see
+    /// [`Self::is_invitable`].
+    ///
     /// If the `cache` is enabled, returns [`ModelError::InvalidPermissions`] if the current user
     /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
     ///
     /// [Create Instant Invite]: Permissions::CREATE_INSTANT_INVITE
-    #[inline]
-    #[cfg(feature = "utils")]
     pub async fn create_invite(
         &self,
         cache_http: impl CacheHttp,
         builder: CreateInvite<'_>,
     ) -> Result<RichInvite> {
+        if !self.is_invitable() {
+            return Err(Error::Model(ModelError::InvalidChannelType));
+        }
+
         builder.execute(cache_http, self.id).await
     }
 
@@ -491,6 +543,98 @@ impl GuildChannel {
         Ok(())
     }
 
+    /// Sets the slowmode of the channel, i.e. how many seconds a user has to wait before sending
+    /// another message. A value of `0` disables slowmode.
+    ///
+    /// This is a shortcut for [`Self::edit`] with [`EditChannel::rate_limit_per_user`] set, and
+    /// updates `self` with the edited channel on success.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotInRange`] if `seconds` is greater than
+    /// [`RATE_LIMIT_PER_USER_MAX`]. Otherwise, see [`Self::edit`].
+    ///
+    /// [`Error::NotInRange`]: crate::Error::NotInRange
+    /// [`RATE_LIMIT_PER_USER_MAX`]: crate::constants::RATE_LIMIT_PER_USER_MAX
+    /// [Manage Channels]: Permissions::MANAGE_CHANNELS
+    #[doc(alias = "rate_limit_per_user")]
+    pub async fn set_slowmode(
+        &mut self,
+        cache_http: impl CacheHttp,
+        seconds: u16,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let max = crate::constants::RATE_LIMIT_PER_USER_MAX;
+        if seconds > max {
+            return Err(crate::Error::NotInRange("seconds", seconds.into(), 0, max.into()));
+        }
+
+        let mut builder = EditChannel::new().rate_limit_per_user(seconds);
+        if let Some(reason) = reason {
+            builder = builder.audit_log_reason(reason);
+        }
+
+        self.edit(cache_http, builder).await
+    }
+
+    /// Syncs the channel's permission overwrites with those of its parent category, replacing
+    /// the current overwrites, and updates `self` with the edited channel on success.
+    ///
+    /// The parent category is taken from the cache if possible, and fetched otherwise.
+    ///
+    /// **Note**: Requires the [Manage Channels] and [Manage Roles] permissions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ModelError::InvalidChannelType`] if the channel is not in a category, or is
+    /// itself a category or a thread.
+    ///
+    /// Returns [`Error::Http`] if the parent could not be fetched or the current user lacks
+    /// permission.
+    ///
+    /// [Manage Channels]: Permissions::MANAGE_CHANNELS
+    /// [Manage Roles]: Permissions::MANAGE_ROLES
+    pub async fn sync_permissions(
+        &mut self,
+        cache_http: impl CacheHttp,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        if self.kind == ChannelType::Category || self.thread_metadata.is_some() {
+            return Err(Error::Model(ModelError::InvalidChannelType));
+        }
+        let parent_id = self.parent_id.ok_or(Error::Model(ModelError::InvalidChannelType))?;
+
+        #[cfg(feature = "cache")]
+        let cached = cache_http.cache().and_then(|cache| {
+            let guild = cache.guild(self.guild_id)?;
+            let parent = guild.channels.get(&parent_id)?;
+            Some((parent.kind, parent.permission_overwrites.clone()))
+        });
+        #[cfg(not(feature = "cache"))]
+        let cached = None;
+
+        let (kind, overwrites) = if let Some(parent) = cached {
+            parent
+        } else {
+            let parent = parent_id.to_channel(&cache_http).await?;
+            let parent = parent.guild().ok_or(Error::Model(ModelError::InvalidChannelType))?;
+            (parent.kind, parent.permission_overwrites)
+        };
+
+        if kind != ChannelType::Category {
+            return Err(Error::Model(ModelError::InvalidChannelType));
+        }
+
+        let mut builder = EditChannel::new().permissions(overwrites);
+        if let Some(reason) = reason {
+            builder = builder.audit_log_reason(reason);
+        }
+
+        self.edit(cache_http, builder).await
+    }
+
     /// Edits a [`Message`] in the channel given its Id.
     ///
     /// Message editing preserves all unchanged message data, with some exceptions for embeds and
@@ -529,6 +673,45 @@ impl GuildChannel {
         Ok(())
     }
 
+    /// Sets the tags applied to this forum post, replacing the current ones.
+    ///
+    /// If the cache is enabled and the parent forum channel is cached, the tags are checked to
+    /// exist in its [`Self::available_tags`] beforehand.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ModelError::UnknownForumTag`] if a tag does not exist in the parent forum.
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    pub async fn set_applied_tags(
+        &mut self,
+        cache_http: impl CacheHttp,
+        tags: &[ForumTagId],
+        reason: Option<&str>,
+    ) -> Result<()> {
+        #[cfg(feature = "cache")]
+        if let Some(cache) = cache_http.cache() {
+            let unknown = self.parent_id.and_then(|parent_id| {
+                let guild = cache.guild(self.guild_id)?;
+                let parent = guild.channels.get(&parent_id)?;
+                tags.iter()
+                    .find(|id| !parent.available_tags.iter().any(|tag| tag.id == **id))
+                    .copied()
+            });
+
+            if let Some(id) = unknown {
+                return Err(Error::Model(ModelError::UnknownForumTag(id)));
+            }
+        }
+
+        let mut builder = EditThread::new().applied_tags(tags.iter().copied());
+        if let Some(reason) = reason {
+            builder = builder.audit_log_reason(reason);
+        }
+
+        self.edit_thread(cache_http, builder).await
+    }
+
     /// Edits the voice state of a given user in a stage channel.
     ///
     /// **Note**: Requires the [Request to Speak] permission. Also requires the [Mute Members]
@@ -659,17 +842,63 @@ impl GuildChannel {
         cache.as_ref().guild(self.guild_id)
     }
 
+    /// Finds the channel directly above this one within the same category in the Cache, i.e. the
+    /// sibling with the next lower [`Self::position`].
+    ///
+    /// Categories are only compared with other categories, and channels without a category only
+    /// with other channels without a category. Ties in position are broken by Id.
+    #[cfg(feature = "cache")]
+    pub fn sibling_above(&self, cache: impl AsRef<Cache>) -> Option<GuildChannel> {
+        let guild = self.guild(&cache)?;
+        self.siblings(&guild)
+            .filter(|c| self.sort_key() > c.sort_key())
+            .max_by_key(|c| c.sort_key())
+            .cloned()
+    }
+
+    /// Finds the channel directly below this one within the same category in the Cache, i.e. the
+    /// sibling with the next higher [`Self::position`].
+    ///
+    /// See [`Self::sibling_above`] for how siblings are determined.
+    #[cfg(feature = "cache")]
+    pub fn sibling_below(&self, cache: impl AsRef<Cache>) -> Option<GuildChannel> {
+        let guild = self.guild(&cache)?;
+        self.siblings(&guild)
+            .filter(|c| self.sort_key() < c.sort_key())
+            .min_by_key(|c| c.sort_key())
+            .cloned()
+    }
+
+    #[cfg(feature = "cache")]
+    fn siblings<'a>(&'a self, guild: &'a Guild) -> impl Iterator<Item = &'a GuildChannel> {
+        let is_category = self.kind == ChannelType::Category;
+        guild.channels.values().filter(move |c| {
+            c.parent_id == self.parent_id && (c.kind == ChannelType::Category) == is_category
+        })
+    }
+
+    #[cfg(feature = "cache")]
+    fn sort_key(&self) -> (u16, ChannelId) {
+        (self.position, self.id)
+    }
+
     /// Gets all of the channel's invites.
     ///
     /// Requires the [Manage Channels] permission.
     ///
     /// # Errors
     ///
+    /// Returns [`ModelError::InvalidChannelType`] if the channel cannot have invites, see
+    /// [`Self::is_invitable`].
+    ///
     /// Returns [`Error::Http`] if the current user lacks permission.
     ///
     /// [Manage Channels]: Permissions::MANAGE_CHANNELS
-    #[inline]
     pub async fn invites(&self, http: impl AsRef<Http>) -> Result<Vec<RichInvite>> {
+        if !self.is_invitable() {
+            return Err(Error::Model(ModelError::InvalidChannelType));
+        }
+
         self.id.invites(http).await
     }
 
@@ -725,6 +954,34 @@ impl GuildChannel {
         &self.name
     }
 
+    /// Returns a human readable name of the channel's voice region, e.g. `US West` for `us-west`.
+    ///
+    /// Returns [`None`] if [`Self::rtc_region`] is [`None`], meaning the region is selected
+    /// automatically. Unknown region codes are returned as-is.
+    #[must_use]
+    pub fn rtc_region_name(&self) -> Option<&str> {
+        let region = self.rtc_region.as_deref()?;
+
+        let name = match region {
+            "brazil" => "Brazil",
+            "hongkong" => "Hong Kong",
+            "india" => "India",
+            "japan" => "Japan",
+            "rotterdam" => "Rotterdam",
+            "russia" => "Russia",
+            "singapore" => "Singapore",
+            "south-korea" => "South Korea",
+            "southafrica" => "South Africa",
+            "sydney" => "Sydney",
+            "us-central" => "US Central",
+            "us-east" => "US East",
+            "us-south" => "US South",
+            "us-west" => "US West",
+            _ => region,
+        };
+        Some(name)
+    }
+
     /// Calculates the permissions of a member.
     ///
     /// The Id of the argument must be a [`Member`] of the [`Guild`] that the channel is in.
@@ -806,19 +1063,47 @@ impl GuildChannel {
     ///
     /// # Errors
     ///
+    /// Returns [`ModelError::InvalidChannelType`] if the channel is not text-based, see
+    /// [`Self::is_text_based`].
+    ///
     /// Returns [`Error::Http`] if the current user lacks permission, or if the channel already has
     /// too many pinned messages.
     ///
     /// [Manage Messages]: Permissions::MANAGE_MESSAGES
-    #[inline]
     pub async fn pin(
         &self,
         http: impl AsRef<Http>,
         message_id: impl Into<MessageId>,
     ) -> Result<()> {
+        if !self.is_text_based() {
+            return Err(Error::Model(ModelError::InvalidChannelType));
+        }
+
         self.id.pin(http, message_id).await
     }
 
+    /// Pins a [`Message`] to the channel, with a reason that will appear in the audit log.
+    ///
+    /// **Note**: Requires the [Manage Messages] permission.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::pin`].
+    ///
+    /// [Manage Messages]: Permissions::MANAGE_MESSAGES
+    pub async fn pin_with_reason(
+        &self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+        reason: &str,
+    ) -> Result<()> {
+        if !self.is_text_based() {
+            return Err(Error::Model(ModelError::InvalidChannelType));
+        }
+
+        self.id.pin_with_reason(http, message_id, reason).await
+    }
+
     /// Gets all channel's pins.
     ///
     /// **Note**: If the current user lacks the [Read Message History] permission an empty [`Vec`]
@@ -826,11 +1111,17 @@ impl GuildChannel {
     ///
     /// # Errors
     ///
+    /// Returns [`ModelError::InvalidChannelType`] if the channel is not text-based, see
+    /// [`Self::is_text_based`].
+    ///
     /// Returns [`Error::Http`] if the current user lacks permission to view the channel.
     ///
     /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
-    #[inline]
     pub async fn pins(&self, http: impl AsRef<Http>) -> Result<Vec<Message>> {
+        if !self.is_text_based() {
+            return Err(Error::Model(ModelError::InvalidChannelType));
+        }
+
         self.id.pins(http).await
     }
 
@@ -964,19 +1255,49 @@ impl GuildChannel {
     ///
     /// # Errors
     ///
+    /// Returns [`ModelError::InvalidChannelType`] if the channel is not text-based, see
+    /// [`Self::is_text_based`].
+    ///
     /// Returns [`Error::Http`] if the current user lacks permission.
     ///
     /// [Manage Messages]: Permissions::MANAGE_MESSAGES
-    #[inline]
     pub async fn unpin(
         &self,
         http: impl AsRef<Http>,
         message_id: impl Into<MessageId>,
     ) -> Result<()> {
+        if !self.is_text_based() {
+            return Err(Error::Model(ModelError::InvalidChannelType));
+        }
+
         self.id.unpin(http, message_id).await
     }
 
-    /// Retrieves the channel's webhooks.
+    /// Unpins a [`Message`] in the channel given by its Id, with a reason that will appear in the
+    /// audit log.
+    ///
+    /// Requires the [Manage Messages] permission.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::unpin`].
+    ///
+    /// [Manage Messages]: Permissions::MANAGE_MESSAGES
+    pub async fn unpin_with_reason(
+        &self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+        reason: &str,
+    ) -> Result<()> {
+        if !self.is_text_based() {
+            return Err(Error::Model(ModelError::InvalidChannelType));
+        }
+
+        self.id.unpin_with_reason(http, message_id, reason).await
+    }
+
+    /// Retrieves the channel's webhooks. To list the webhooks of every channel in the guild, use
+    /// [`GuildId::webhooks`].
     ///
     /// **Note**: Requires the [Manage Webhooks] permission.
     ///
@@ -1033,6 +1354,21 @@ impl GuildChannel {
         }
     }
 
+    /// Checks whether nobody is connected to this voice or stage channel, per the voice states of
+    /// the guild in the Cache.
+    ///
+    /// Returns [`None`] if the channel is not a [`ChannelType::Voice`] or [`ChannelType::Stage`]
+    /// channel, or if its guild is not in the Cache.
+    #[cfg(feature = "cache")]
+    pub fn is_empty_voice(&self, cache: impl AsRef<Cache>) -> Option<bool> {
+        if !matches!(self.kind, ChannelType::Voice | ChannelType::Stage) {
+            return None;
+        }
+
+        let guild = self.guild(&cache)?;
+        Some(!guild.voice_states.values().any(|v| v.channel_id == Some(self.id)))
+    }
+
     /// Returns a builder which can be awaited to obtain a message or stream of messages sent in
     /// this guild channel.
     #[cfg(feature = "collector")]
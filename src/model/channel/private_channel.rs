@@ -65,12 +65,28 @@ impl PrivateChannel {
         self.id.create_reaction(http, message_id, reaction_type).await
     }
 
+    /// Closes the channel, returning the closed channel.
+    ///
+    /// This does not delete the contents of the channel, and is equivalent to closing a private
+    /// channel on the client. The message history is kept, and the channel can be re-opened at any
+    /// time, e.g. via [`User::create_dm_channel`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the channel no longer exists.
+    #[inline]
+    pub async fn close(&self, http: impl AsRef<Http>) -> Result<PrivateChannel> {
+        self.id.delete(http).await?.private().ok_or(Error::Model(ModelError::InvalidChannelType))
+    }
+
     /// Deletes the channel. This does not delete the contents of the channel, and is equivalent to
     /// closing a private channel on the client, which can be re-opened.
+    ///
+    /// This is the same as [`Self::close`], which better describes what happens.
     #[allow(clippy::missing_errors_doc)]
     #[inline]
     pub async fn delete(&self, http: impl AsRef<Http>) -> Result<PrivateChannel> {
-        self.id.delete(http).await?.private().ok_or(Error::Model(ModelError::InvalidChannelType))
+        self.close(http).await
     }
 
     /// Deletes all messages by Ids from the given vector in the channel.
@@ -3,6 +3,7 @@
 use std::error::Error as StdError;
 use std::fmt;
 
+use super::id::ForumTagId;
 use super::Permissions;
 
 /// An error returned from the [`model`] module.
@@ -151,6 +152,41 @@ pub enum Error {
     StickerAmount,
     /// When attempting to edit a voice message.
     CannotEditVoiceMessage,
+    /// Indicates that a chat input command is missing its description, or that a user or message
+    /// context menu command has a non-empty description.
+    InvalidCommandDescription,
+    /// When attempting to create an emoji in a guild that has no slots left for that kind of
+    /// emoji.
+    EmojiSlotsFull,
+    /// Indicates that a scheduled event's field, given by name, is missing or not allowed for the
+    /// event's type.
+    InvalidScheduledEventField(&'static str),
+    /// Indicates that the guild lacks the feature, given by name, that is required for the
+    /// action.
+    MissingGuildFeature(&'static str),
+    /// Indicates that an emoji name is not between 2 and 32 characters long, or contains
+    /// characters other than `[a-zA-Z0-9_]`.
+    InvalidEmojiName,
+    /// Indicates that a sticker name is not between 2 and 30 characters long.
+    InvalidStickerName,
+    /// Indicates that a sticker description is neither empty nor between 2 and 100 characters
+    /// long.
+    InvalidStickerDescription,
+    /// Indicates that a sticker's tags are over the 200 characters limit.
+    InvalidStickerTags,
+    /// Indicates that a sticker file is not a PNG, APNG, GIF or Lottie JSON file.
+    InvalidStickerFormat,
+    /// Indicates that the localized command name for the given locale is not 1-32 characters
+    /// long, or that it contains characters other than lowercase letters, numbers, `-` and `_`.
+    InvalidCommandNameLocalization(String),
+    /// Indicates that the localized command description for the given locale is not 1-100
+    /// characters long.
+    InvalidCommandDescriptionLocalization(String),
+    /// Indicates that the forum tag with the given Id does not exist in the forum channel.
+    UnknownForumTag(ForumTagId),
+    /// Indicates that a category channel was given a parent category, as categories can not be
+    /// nested.
+    NestedCategory,
 }
 
 impl Error {
@@ -199,6 +235,42 @@ impl fmt::Display for Error {
             Self::NoStickerFileSet => f.write_str("Sticker file is not set."),
             Self::StickerAmount => f.write_str("Too many stickers in a message."),
             Self::CannotEditVoiceMessage => f.write_str("Cannot edit voice message."),
+            Self::InvalidCommandDescription => {
+                f.write_str("Command description does not match the command type.")
+            },
+            Self::EmojiSlotsFull => f.write_str("No emoji slots left in the guild."),
+            Self::InvalidScheduledEventField(field) => {
+                write!(f, "Field `{field}` is missing or not allowed for the event type.")
+            },
+            Self::MissingGuildFeature(feature) => {
+                write!(f, "The guild lacks the `{feature}` feature required for the action.")
+            },
+            Self::InvalidEmojiName => f.write_str(
+                "Emoji names must be 2-32 characters long and only contain `[a-zA-Z0-9_]`.",
+            ),
+            Self::InvalidStickerName => f.write_str("Sticker names must be 2-30 characters long."),
+            Self::InvalidStickerDescription => {
+                f.write_str("Sticker descriptions must be empty or 2-100 characters long.")
+            },
+            Self::InvalidStickerTags => {
+                f.write_str("Sticker tags must be at most 200 characters long.")
+            },
+            Self::InvalidStickerFormat => {
+                f.write_str("Sticker files must be PNG, APNG, GIF or Lottie JSON files.")
+            },
+            Self::InvalidCommandNameLocalization(locale) => write!(
+                f,
+                "Command name for locale `{locale}` must be 1-32 characters long and only contain \
+                 lowercase letters, numbers, `-` and `_`."
+            ),
+            Self::InvalidCommandDescriptionLocalization(locale) => write!(
+                f,
+                "Command description for locale `{locale}` must be 1-100 characters long."
+            ),
+            Self::UnknownForumTag(id) => write!(f, "Forum tag {id} does not exist in the forum."),
+            Self::NestedCategory => {
+                f.write_str("Categories can not be nested in other categories.")
+            },
         }
     }
 }
@@ -174,7 +174,7 @@ pub struct GuildCreateEvent {
 // Manual impl needed to insert guild_id fields in GuildChannel, Member, Role
 impl<'de> Deserialize<'de> for GuildCreateEvent {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
-        let mut guild: Guild = Guild::deserialize(deserializer)?;
+        let mut guild = <Guild as Deserialize>::deserialize(deserializer)?;
         guild.channels.values_mut().for_each(|x| x.guild_id = guild.id);
         guild.members.values_mut().for_each(|x| x.guild_id = guild.id);
         guild.roles.values_mut().for_each(|x| x.guild_id = guild.id);
@@ -1099,6 +1099,13 @@ pub struct MessagePollVoteRemoveEvent {
 pub enum GatewayEvent {
     Dispatch(u64, Event),
     Heartbeat(u64),
+    /// Discord requested the client to reconnect (opcode 7).
+    ///
+    /// The client should close the connection and reconnect, then resume the session rather than
+    /// identifying anew. [`Shard`]s handle this by returning a resume [`ShardAction::Reconnect`].
+    ///
+    /// [`Shard`]: crate::gateway::Shard
+    /// [`ShardAction::Reconnect`]: crate::gateway::ShardAction::Reconnect
     Reconnect,
     /// Whether the session can be resumed.
     InvalidateSession(bool),
@@ -1365,3 +1372,15 @@ impl Event {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::GatewayEvent;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn reconnect_opcode() {
+        let event: GatewayEvent = from_value(json!({"op": 7, "d": null})).unwrap();
+        assert!(matches!(event, GatewayEvent::Reconnect));
+    }
+}
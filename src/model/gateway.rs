@@ -605,6 +605,107 @@ impl GatewayIntents {
         // See: https://github.com/bitflags/bitflags/issues/180
         Self::GUILD_MEMBERS.union(Self::GUILD_PRESENCES).union(Self::MESSAGE_CONTENT)
     }
+
+    /// Gets the subset of the included intents that are privileged, i.e. those that must be
+    /// enabled in the Developer Portal before identifying with them.
+    ///
+    /// To only check whether any privileged intent is included, see `is_privileged`, which
+    /// requires the `model` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use serenity::model::gateway::GatewayIntents;
+    ///
+    /// let intents = GatewayIntents::GUILDS | GatewayIntents::MESSAGE_CONTENT;
+    /// assert_eq!(intents.privileged_subset(), GatewayIntents::MESSAGE_CONTENT);
+    /// ```
+    #[must_use]
+    pub const fn privileged_subset(self) -> GatewayIntents {
+        self.intersection(Self::privileged())
+    }
+
+    /// Gets the intents required to receive the gateway event with the given name, as returned
+    /// by [`Event::name`]. If multiple intents are returned, enabling any one of them is enough,
+    /// e.g. [`Self::GUILD_MESSAGES`] for messages in guilds and [`Self::DIRECT_MESSAGES`] for
+    /// messages in DMs.
+    ///
+    /// Returns an empty set for events which are always sent, as well as for unknown events.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use serenity::model::gateway::GatewayIntents;
+    ///
+    /// let required = GatewayIntents::required_for("GUILD_MEMBER_ADD");
+    /// assert_eq!(required, GatewayIntents::GUILD_MEMBERS);
+    /// assert!(required.is_privileged());
+    /// ```
+    ///
+    /// [`Event::name`]: crate::model::event::Event::name
+    #[must_use]
+    pub fn required_for(event_name: &str) -> GatewayIntents {
+        match event_name {
+            "GUILD_CREATE"
+            | "GUILD_UPDATE"
+            | "GUILD_DELETE"
+            | "GUILD_ROLE_CREATE"
+            | "GUILD_ROLE_UPDATE"
+            | "GUILD_ROLE_DELETE"
+            | "CHANNEL_CREATE"
+            | "CHANNEL_UPDATE"
+            | "CHANNEL_DELETE"
+            | "THREAD_CREATE"
+            | "THREAD_UPDATE"
+            | "THREAD_DELETE"
+            | "THREAD_LIST_SYNC"
+            | "THREAD_MEMBER_UPDATE"
+            | "STAGE_INSTANCE_CREATE"
+            | "STAGE_INSTANCE_UPDATE"
+            | "STAGE_INSTANCE_DELETE" => Self::GUILDS,
+            "CHANNEL_PINS_UPDATE" => Self::GUILDS | Self::DIRECT_MESSAGES,
+            "THREAD_MEMBERS_UPDATE" => Self::GUILDS | Self::GUILD_MEMBERS,
+            "GUILD_MEMBER_ADD" | "GUILD_MEMBER_UPDATE" | "GUILD_MEMBER_REMOVE" => {
+                Self::GUILD_MEMBERS
+            },
+            "GUILD_AUDIT_LOG_ENTRY_CREATE" | "GUILD_BAN_ADD" | "GUILD_BAN_REMOVE" => {
+                Self::GUILD_MODERATION
+            },
+            "GUILD_EMOJIS_UPDATE" | "GUILD_STICKERS_UPDATE" => Self::GUILD_EMOJIS_AND_STICKERS,
+            "GUILD_INTEGRATIONS_UPDATE"
+            | "INTEGRATION_CREATE"
+            | "INTEGRATION_UPDATE"
+            | "INTEGRATION_DELETE" => Self::GUILD_INTEGRATIONS,
+            "WEBHOOKS_UPDATE" => Self::GUILD_WEBHOOKS,
+            "INVITE_CREATE" | "INVITE_DELETE" => Self::GUILD_INVITES,
+            "VOICE_STATE_UPDATE" => Self::GUILD_VOICE_STATES,
+            "PRESENCE_UPDATE" => Self::GUILD_PRESENCES,
+            "MESSAGE_CREATE" | "MESSAGE_UPDATE" | "MESSAGE_DELETE" => {
+                Self::GUILD_MESSAGES | Self::DIRECT_MESSAGES
+            },
+            "MESSAGE_DELETE_BULK" => Self::GUILD_MESSAGES,
+            "MESSAGE_REACTION_ADD"
+            | "MESSAGE_REACTION_REMOVE"
+            | "MESSAGE_REACTION_REMOVE_ALL"
+            | "MESSAGE_REACTION_REMOVE_EMOJI" => {
+                Self::GUILD_MESSAGE_REACTIONS | Self::DIRECT_MESSAGE_REACTIONS
+            },
+            "TYPING_START" => Self::GUILD_MESSAGE_TYPING | Self::DIRECT_MESSAGE_TYPING,
+            "GUILD_SCHEDULED_EVENT_CREATE"
+            | "GUILD_SCHEDULED_EVENT_UPDATE"
+            | "GUILD_SCHEDULED_EVENT_DELETE"
+            | "GUILD_SCHEDULED_EVENT_USER_ADD"
+            | "GUILD_SCHEDULED_EVENT_USER_REMOVE" => Self::GUILD_SCHEDULED_EVENTS,
+            "AUTO_MODERATION_RULE_CREATE"
+            | "AUTO_MODERATION_RULE_UPDATE"
+            | "AUTO_MODERATION_RULE_DELETE" => Self::AUTO_MODERATION_CONFIGURATION,
+            "AUTO_MODERATION_ACTION_EXECUTION" => Self::AUTO_MODERATION_EXECUTION,
+            "MESSAGE_POLL_VOTE_ADD" | "MESSAGE_POLL_VOTE_REMOVE" => {
+                Self::GUILD_MESSAGE_POLLS | Self::DIRECT_MESSAGE_POLLS
+            },
+            _ => Self::empty(),
+        }
+    }
 }
 
 #[cfg(feature = "model")]
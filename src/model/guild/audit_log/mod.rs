@@ -317,6 +317,25 @@ pub struct AuditLogs {
     pub webhooks: HashMap<WebhookId, Webhook>,
 }
 
+impl AuditLogs {
+    /// Returns the most recent entry targeting the given entity that was created at or after
+    /// `since`, if any.
+    ///
+    /// This is useful for correlating a gateway event, such as a member being removed, with the
+    /// audit log entry of the moderator responsible for it.
+    #[must_use]
+    pub fn most_recent_for(
+        &self,
+        target_id: GenericId,
+        since: Timestamp,
+    ) -> Option<&AuditLogEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.target_id == Some(target_id) && entry.id.created_at() >= since)
+            .max_by_key(|entry| entry.id)
+    }
+}
+
 /// Partial version of [`Integration`], used in [`AuditLogs::integrations`].
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/audit-log#audit-log-object-example-partial-integration-object).
@@ -487,4 +506,44 @@ mod tests {
 
         assert!(matches!(value.action, Action::Unknown(234)));
     }
+
+    #[test]
+    fn most_recent_for() {
+        use crate::json::{self, json};
+
+        // Snowflakes encode their creation time in milliseconds since the Discord epoch.
+        let entry = |secs: u64, target_id: u64| -> AuditLogEntry {
+            let id = ((secs * 1000) - 1_420_070_400_000) << 22;
+            json::from_value(json!({
+                "id": id.to_string(),
+                "target_id": target_id.to_string(),
+                "action_type": 20,
+                "user_id": "1",
+            }))
+            .unwrap()
+        };
+
+        let logs = AuditLogs {
+            entries: vec![
+                entry(1_700_000_300, 2),
+                entry(1_700_000_200, 3),
+                entry(1_700_000_100, 3),
+            ],
+            auto_moderation_rules: vec![],
+            application_commands: vec![],
+            guild_scheduled_events: vec![],
+            integrations: vec![],
+            threads: vec![],
+            users: HashMap::new(),
+            webhooks: HashMap::new(),
+        };
+
+        let since = Timestamp::from_unix_timestamp(1_700_000_050).unwrap();
+        let found = logs.most_recent_for(GenericId::new(3), since).unwrap();
+        assert_eq!(found.id, entry(1_700_000_200, 3).id);
+
+        let since = Timestamp::from_unix_timestamp(1_700_000_250).unwrap();
+        assert!(logs.most_recent_for(GenericId::new(3), since).is_none());
+        assert!(logs.most_recent_for(GenericId::new(2), since).is_some());
+    }
 }
@@ -0,0 +1,48 @@
+use crate::model::prelude::*;
+
+/// The settings of a guild in Server Discovery.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/discovery#discovery-metadata-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildDiscoveryMetadata {
+    /// The Id of the guild.
+    pub guild_id: GuildId,
+    /// The Id of the primary discovery category of the guild.
+    pub primary_category_id: u64,
+    /// The discovery search keywords of the guild, up to 10.
+    pub keywords: Option<Vec<String>>,
+    /// Whether the guild is shown as a source through custom emoji usage.
+    pub emoji_discoverability_enabled: bool,
+    /// When the guild's partner application was accepted or denied, if any.
+    pub partner_actioned_timestamp: Option<Timestamp>,
+    /// When the guild applied for partnership, if it has a pending application.
+    pub partner_application_timestamp: Option<Timestamp>,
+    /// The Ids of the discovery subcategories of the guild, up to 5.
+    pub category_ids: Vec<u64>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn deserialize_discovery_metadata() {
+        let metadata: GuildDiscoveryMetadata = from_value(json!({
+            "guild_id": "197038439483310086",
+            "primary_category_id": 1,
+            "keywords": ["discord", "official"],
+            "emoji_discoverability_enabled": true,
+            "partner_actioned_timestamp": null,
+            "partner_application_timestamp": null,
+            "category_ids": [14]
+        }))
+        .unwrap();
+
+        assert_eq!(metadata.guild_id, GuildId::new(197_038_439_483_310_086));
+        assert_eq!(metadata.keywords.unwrap(), ["discord", "official"]);
+        assert_eq!(metadata.category_ids, [14]);
+    }
+}
@@ -9,8 +9,12 @@ use crate::internal::prelude::*;
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::model::id::GuildId;
 use crate::model::id::{EmojiId, RoleId};
+#[cfg(feature = "model")]
+use crate::model::misc::ImageFormat;
 use crate::model::user::User;
 use crate::model::utils::default_true;
+#[cfg(feature = "model")]
+use crate::model::utils::emoji_url;
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::model::ModelError;
 
@@ -180,9 +184,26 @@ impl Emoji {
     #[inline]
     #[must_use]
     pub fn url(&self) -> String {
-        let extension = if self.animated { "gif" } else { "png" };
-        cdn!("/emojis/{}.{}", self.id, extension)
+        let format = if self.animated { ImageFormat::Gif } else { ImageFormat::Png };
+        emoji_url(self.id, self.animated, Some(format), None)
     }
+
+    /// Generates a URL to the emoji's image in the given format and size.
+    ///
+    /// If no format is given, GIF is used for animated emojis and WEBP otherwise. See
+    /// [`ImageFormat`] for how the size is rounded.
+    #[must_use]
+    pub fn url_sized(&self, format: Option<ImageFormat>, size: u16) -> String {
+        emoji_url(self.id, self.animated, format, Some(size))
+    }
+}
+
+/// Whether the given name is a valid name for a custom emoji, i.e. between 2 and 32 characters
+/// long, and only consisting of alphanumeric ASCII characters and underscores.
+#[cfg(feature = "model")]
+pub(crate) fn is_valid_emoji_name(name: &str) -> bool {
+    (2..=32).contains(&name.len())
+        && name.bytes().all(|byte| byte.is_ascii_alphanumeric() || byte == b'_')
 }
 
 impl fmt::Display for Emoji {
@@ -216,3 +237,40 @@ impl From<&Emoji> for EmojiId {
         emoji.id
     }
 }
+
+#[cfg(all(test, feature = "model"))]
+mod test {
+    use super::{is_valid_emoji_name, Emoji};
+    use crate::model::misc::ImageFormat;
+
+    #[test]
+    fn emoji_name_validation() {
+        assert!(is_valid_emoji_name("ok"));
+        assert!(is_valid_emoji_name("party_parrot_2"));
+        assert!(is_valid_emoji_name(&"a".repeat(32)));
+
+        assert!(!is_valid_emoji_name("a"));
+        assert!(!is_valid_emoji_name(&"a".repeat(33)));
+        assert!(!is_valid_emoji_name("no spaces"));
+        assert!(!is_valid_emoji_name("no-dashes"));
+        assert!(!is_valid_emoji_name("\u{e9}moji"));
+    }
+
+    #[test]
+    fn emoji_url() {
+        let mut emoji: Emoji = crate::json::from_value(crate::json::json!({
+            "id": "1",
+            "name": "blobwave",
+        }))
+        .unwrap();
+        assert_eq!(emoji.url(), "https://cdn.discordapp.com/emojis/1.png");
+        assert_eq!(emoji.url_sized(None, 100), "https://cdn.discordapp.com/emojis/1.webp?size=128");
+
+        emoji.animated = true;
+        assert_eq!(emoji.url(), "https://cdn.discordapp.com/emojis/1.gif");
+        assert_eq!(
+            emoji.url_sized(Some(ImageFormat::Png), 16),
+            "https://cdn.discordapp.com/emojis/1.png?size=16"
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use crate::model::prelude::*;
+
+/// A builder for constructing a [`Guild`] locally, e.g. when rehydrating guild state from a
+/// database snapshot outside the gateway lifecycle.
+///
+/// Created by [`Guild::builder`]. Fields which are only sent over the gateway, such as
+/// [`Guild::joined_at`] or [`Guild::large`], are left at their defaults unless set explicitly.
+///
+/// # Examples
+///
+/// ```rust
+/// # use serenity::model::prelude::*;
+/// # fn run(roles: Vec<Role>, channels: Vec<GuildChannel>) {
+/// let guild = Guild::builder(GuildId::new(1), "My Guild")
+///     .owner_id(UserId::new(2))
+///     .roles(roles)
+///     .channels(channels)
+///     .build();
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct GuildBuilder {
+    guild: Guild,
+    member_count: Option<u64>,
+}
+
+impl GuildBuilder {
+    /// Constructs a new builder for a guild with the given Id and name.
+    pub fn new(id: GuildId, name: impl Into<String>) -> Self {
+        Self {
+            guild: Guild {
+                id,
+                name: name.into(),
+                preferred_locale: "en-US".into(),
+                ..Default::default()
+            },
+            member_count: None,
+        }
+    }
+
+    /// Sets the Id of the user who owns the guild.
+    pub fn owner_id(mut self, owner_id: UserId) -> Self {
+        self.guild.owner_id = owner_id;
+        self
+    }
+
+    /// Sets the hash of the guild's icon.
+    pub fn icon(mut self, icon: Option<ImageHash>) -> Self {
+        self.guild.icon = icon;
+        self
+    }
+
+    /// Sets the description of the guild.
+    pub fn description(mut self, description: Option<String>) -> Self {
+        self.guild.description = description;
+        self
+    }
+
+    /// Sets the features of the guild, such as `"COMMUNITY"`.
+    pub fn features(mut self, features: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.guild.features = features.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the preferred locale of the guild. Defaults to `en-US`.
+    pub fn preferred_locale(mut self, locale: impl Into<String>) -> Self {
+        self.guild.preferred_locale = locale.into();
+        self
+    }
+
+    /// Sets the verification level of the guild.
+    pub fn verification_level(mut self, level: VerificationLevel) -> Self {
+        self.guild.verification_level = level;
+        self
+    }
+
+    /// Sets the premium tier of the guild.
+    pub fn premium_tier(mut self, tier: PremiumTier) -> Self {
+        self.guild.premium_tier = tier;
+        self
+    }
+
+    /// Sets when the current user joined the guild.
+    pub fn joined_at(mut self, joined_at: Timestamp) -> Self {
+        self.guild.joined_at = joined_at;
+        self
+    }
+
+    /// Sets whether the guild is considered large by Discord.
+    pub fn large(mut self, large: bool) -> Self {
+        self.guild.large = large;
+        self
+    }
+
+    /// Sets the total number of members in the guild. Defaults to the number of members added to
+    /// the builder.
+    pub fn member_count(mut self, member_count: u64) -> Self {
+        self.member_count = Some(member_count);
+        self
+    }
+
+    /// Adds a role to the guild, replacing any role with the same Id.
+    pub fn role(mut self, mut role: Role) -> Self {
+        role.guild_id = self.guild.id;
+        self.guild.roles.insert(role.id, role);
+        self.guild.refresh_sorted_role_ids();
+        self
+    }
+
+    /// Adds multiple roles to the guild. See [`Self::role`].
+    pub fn roles(self, roles: impl IntoIterator<Item = Role>) -> Self {
+        roles.into_iter().fold(self, Self::role)
+    }
+
+    /// Adds a channel to the guild, replacing any channel with the same Id.
+    ///
+    /// **Note**: Threads should be added with [`Self::thread`] instead.
+    pub fn channel(mut self, mut channel: GuildChannel) -> Self {
+        channel.guild_id = self.guild.id;
+        self.guild.channels.insert(channel.id, channel);
+        self
+    }
+
+    /// Adds multiple channels to the guild. See [`Self::channel`].
+    pub fn channels(self, channels: impl IntoIterator<Item = GuildChannel>) -> Self {
+        channels.into_iter().fold(self, Self::channel)
+    }
+
+    /// Adds an active thread to the guild.
+    pub fn thread(mut self, mut thread: GuildChannel) -> Self {
+        thread.guild_id = self.guild.id;
+        self.guild.threads.push(thread);
+        self
+    }
+
+    /// Adds a member to the guild, replacing any member with the same user Id.
+    pub fn member(mut self, mut member: Member) -> Self {
+        member.guild_id = self.guild.id;
+        self.guild.members.insert(member.user.id, member);
+        self
+    }
+
+    /// Adds multiple members to the guild. See [`Self::member`].
+    pub fn members(self, members: impl IntoIterator<Item = Member>) -> Self {
+        members.into_iter().fold(self, Self::member)
+    }
+
+    /// Adds an emoji to the guild, replacing any emoji with the same Id.
+    pub fn emoji(mut self, emoji: Emoji) -> Self {
+        self.guild.emojis.insert(emoji.id, emoji);
+        self
+    }
+
+    /// Adds a sticker to the guild, replacing any sticker with the same Id.
+    pub fn sticker(mut self, mut sticker: Sticker) -> Self {
+        sticker.guild_id = Some(self.guild.id);
+        self.guild.stickers.insert(sticker.id, sticker);
+        self
+    }
+
+    /// Builds the [`Guild`].
+    #[must_use]
+    pub fn build(self) -> Guild {
+        let mut guild = self.guild;
+        guild.member_count = self.member_count.unwrap_or(guild.members.len() as u64);
+        guild
+    }
+}
+
+impl Guild {
+    /// Returns a builder for constructing a guild locally with the given Id and name.
+    ///
+    /// See [`GuildBuilder`] for details.
+    pub fn builder(id: GuildId, name: impl Into<String>) -> GuildBuilder {
+        GuildBuilder::new(id, name)
+    }
+}
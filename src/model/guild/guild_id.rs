@@ -6,19 +6,24 @@ use futures::stream::Stream;
 #[cfg(feature = "model")]
 use crate::builder::{
     AddMember,
+    AuditLogFilter,
     Builder,
     CreateChannel,
     CreateCommand,
     CreateScheduledEvent,
+    CreateSoundboardSound,
     CreateSticker,
     EditAutoModRule,
     EditCommandPermissions,
     EditGuild,
+    EditGuildDiscoveryMetadata,
+    EditGuildOnboarding,
     EditGuildWelcomeScreen,
     EditGuildWidget,
     EditMember,
     EditRole,
     EditScheduledEvent,
+    EditSoundboardSound,
     EditSticker,
 };
 #[cfg(all(feature = "cache", feature = "model"))]
@@ -161,9 +166,14 @@ impl GuildId {
     /// Returns the created [`Member`] object, or nothing if the user is already a member of the
     /// guild.
     ///
+    /// **Note**: The access token must have been granted the `guilds.join` scope, and the current
+    /// user must be in the guild with the [Create Instant Invite] permission.
+    ///
     /// # Errors
     ///
     /// Returns [`Error::Http`] if the current user lacks permission, or if invalid data is given.
+    ///
+    /// [Create Instant Invite]: Permissions::CREATE_INSTANT_INVITE
     #[inline]
     pub async fn add_member(
         self,
@@ -249,6 +259,46 @@ impl GuildId {
         http.as_ref().ban_user(self, user, dmd, reason).await
     }
 
+    /// Ban a [`User`] from the guild, deleting the messages they sent in the last
+    /// `delete_message_seconds` seconds, up to 7 days.
+    ///
+    /// **Note**: Requires the [Ban Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotInRange`] if `delete_message_seconds` is over
+    /// [`BAN_DELETE_MESSAGE_SECONDS_MAX`].
+    ///
+    /// Returns [`Error::ExceededLimit`] if `reason` is too long.
+    ///
+    /// Also can return [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [`BAN_DELETE_MESSAGE_SECONDS_MAX`]: crate::constants::BAN_DELETE_MESSAGE_SECONDS_MAX
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    pub async fn ban_with_message_deletion(
+        self,
+        http: impl AsRef<Http>,
+        user: impl Into<UserId>,
+        delete_message_seconds: u32,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let max = crate::constants::BAN_DELETE_MESSAGE_SECONDS_MAX;
+        if delete_message_seconds > max {
+            let secs = delete_message_seconds.into();
+            return Err(Error::NotInRange("delete_message_seconds", secs, 0, max.into()));
+        }
+
+        if let Some(reason) = reason {
+            if reason.chars().count() > 512 {
+                return Err(Error::ExceededLimit(reason.to_string(), 512));
+            }
+        }
+
+        http.as_ref()
+            .ban_user_with_message_deletion(self, user.into(), delete_message_seconds, reason)
+            .await
+    }
+
     /// Bans multiple users from the guild, returning the users that were and weren't banned, and
     /// optionally deleting messages that are younger than the provided `delete_message_seconds`.
     ///
@@ -322,6 +372,59 @@ impl GuildId {
         http.as_ref().get_audit_logs(self, action_type, user_id, before, limit).await
     }
 
+    /// Gets a list of the guild's audit log entries matching the given filter.
+    ///
+    /// Refer to the documentation for [`AuditLogFilter`] for the available filters.
+    ///
+    /// **Note**: Requires the [View Audit Log] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [View Audit Log]: Permissions::VIEW_AUDIT_LOG
+    #[inline]
+    pub async fn audit_log_entries(
+        self,
+        http: impl AsRef<Http>,
+        filter: AuditLogFilter,
+    ) -> Result<AuditLogs> {
+        filter.execute(http.as_ref(), self).await
+    }
+
+    /// Finds the most recent audit log entry of the given action that targets `target_id` and was
+    /// created within the last `within`.
+    ///
+    /// This is used to find out who is responsible for an event received over the gateway, e.g.
+    /// which moderator kicked a member. As audit log entries may be created slightly after the
+    /// event is received, a short retry may be needed if [`None`] is returned.
+    ///
+    /// See [`AuditLogs::most_recent_for`] for matching entries that were already fetched.
+    ///
+    /// **Note**: Requires the [View Audit Log] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [View Audit Log]: Permissions::VIEW_AUDIT_LOG
+    pub async fn find_audit_entry(
+        self,
+        http: impl AsRef<Http>,
+        action: audit_log::Action,
+        target_id: impl Into<GenericId>,
+        within: std::time::Duration,
+    ) -> Result<Option<AuditLogEntry>> {
+        let within = i64::try_from(within.as_secs()).unwrap_or(i64::MAX);
+        let since = Timestamp::from_unix_timestamp(Timestamp::now().unix_timestamp() - within)
+            .unwrap_or_default();
+
+        let filter = AuditLogFilter::new().action_type(action);
+        let logs = self.audit_log_entries(http, filter).await?;
+
+        Ok(logs.most_recent_for(target_id.into(), since).cloned())
+    }
+
     /// Gets all of the guild's channels over the REST API.
     ///
     /// # Errors
@@ -362,6 +465,8 @@ impl GuildId {
     ///
     /// # Errors
     ///
+    /// Returns [`ModelError::NestedCategory`] if a category channel is given a parent category.
+    ///
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
     /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
     ///
@@ -389,24 +494,40 @@ impl GuildId {
     ///
     /// # Errors
     ///
-    /// Returns [`Error::Http`] if the current user lacks permission, if the name is too long, or
-    /// if the image is too big.
+    /// Returns [`ModelError::InvalidEmojiName`] if the name is not between 2 and 32 characters
+    /// long, or contains characters other than `[a-zA-Z0-9_]`.
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the image is too big.
     ///
     /// [`EditProfile::avatar`]: crate::builder::EditProfile::avatar
     /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
-    #[inline]
     pub async fn create_emoji(
         self,
         http: impl AsRef<Http>,
         name: &str,
         image: &str,
     ) -> Result<Emoji> {
+        self.create_emoji_(http.as_ref(), name, image, &[]).await
+    }
+
+    pub(crate) async fn create_emoji_(
+        self,
+        http: &Http,
+        name: &str,
+        image: &str,
+        roles: &[RoleId],
+    ) -> Result<Emoji> {
+        if !super::emoji::is_valid_emoji_name(name) {
+            return Err(Error::Model(ModelError::InvalidEmojiName));
+        }
+
         let map = json!({
             "name": name,
             "image": image,
+            "roles": roles,
         });
 
-        http.as_ref().create_emoji(self, &map, None).await
+        http.create_emoji(self, &map, None).await
     }
 
     /// Creates an integration for the guild.
@@ -461,6 +582,9 @@ impl GuildId {
     ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::InvalidScheduledEventField`] if a field required by the event type
+    /// is missing, or a field not allowed for it is set.
+    ///
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
     /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
     ///
@@ -473,6 +597,25 @@ impl GuildId {
         builder.execute(cache_http, self).await
     }
 
+    /// Creates a new soundboard sound in the guild with the data set.
+    ///
+    /// **Note**: Requires the [Create Guild Expressions] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
+    /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    #[inline]
+    pub async fn create_soundboard_sound(
+        self,
+        cache_http: impl CacheHttp,
+        builder: CreateSoundboardSound<'_>,
+    ) -> Result<SoundboardSound> {
+        builder.execute(cache_http, self).await
+    }
+
     /// Creates a new sticker in the guild with the data set, if any.
     ///
     /// **Note**: Requires the [Create Guild Expressions] permission.
@@ -589,6 +732,28 @@ impl GuildId {
         http.as_ref().delete_scheduled_event(self, event_id.into()).await
     }
 
+    /// Deletes a [`SoundboardSound`] by id from the guild.
+    ///
+    /// **Note**: If the sound was created by the current user, requires either the [Create Guild
+    /// Expressions] or the [Manage Guild Expressions] permission. Otherwise, the [Manage Guild
+    /// Expressions] permission is required.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if a sound with that id
+    /// does not exist.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    /// [Manage Guild Expressions]: Permissions::MANAGE_GUILD_EXPRESSIONS
+    #[inline]
+    pub async fn delete_soundboard_sound(
+        self,
+        http: impl AsRef<Http>,
+        sound_id: impl Into<SoundId>,
+    ) -> Result<()> {
+        http.as_ref().delete_soundboard_sound(self, sound_id.into(), None).await
+    }
+
     /// Deletes a [`Sticker`] by id from the guild.
     ///
     /// **Note**: If the sticker was created by the current user, requires either the [Create Guild
@@ -732,8 +897,9 @@ impl GuildId {
         self,
         http: impl AsRef<Http>,
         new_nickname: Option<&str>,
+        audit_log_reason: Option<&str>,
     ) -> Result<()> {
-        http.as_ref().edit_nickname(self, new_nickname, None).await
+        http.as_ref().edit_nickname(self, new_nickname, audit_log_reason).await
     }
 
     /// Edits a [`Role`], optionally setting its new fields.
@@ -799,6 +965,45 @@ impl GuildId {
         builder.execute(cache_http, (self, event_id.into())).await
     }
 
+    /// Edits a soundboard sound.
+    ///
+    /// **Note**: If the sound was created by the current user, requires either the [Create Guild
+    /// Expressions] or the [Manage Guild Expressions] permission. Otherwise, the [Manage Guild
+    /// Expressions] permission is required.
+    ///
+    /// # Examples
+    ///
+    /// Lower the volume of a sound:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// use serenity::builder::EditSoundboardSound;
+    /// use serenity::model::id::{GuildId, SoundId};
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http: Http = unimplemented!();
+    /// let builder = EditSoundboardSound::new().volume(0.5);
+    /// let _ = GuildId::new(7).edit_soundboard_sound(&http, SoundId::new(7), builder).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if invalid data is given.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    /// [Manage Guild Expressions]: Permissions::MANAGE_GUILD_EXPRESSIONS
+    #[inline]
+    pub async fn edit_soundboard_sound(
+        self,
+        cache_http: impl CacheHttp,
+        sound_id: impl Into<SoundId>,
+        builder: EditSoundboardSound<'_>,
+    ) -> Result<SoundboardSound> {
+        builder.execute(cache_http, (self, sound_id.into())).await
+    }
+
     /// Edits a sticker.
     ///
     /// **Note**: If the sticker was created by the current user, requires either the [Create Guild
@@ -864,6 +1069,88 @@ impl GuildId {
         http.as_ref().edit_role_position(self, role_id.into(), position, None).await
     }
 
+    /// Edits the positions of multiple [`Role`]s in a single request.
+    ///
+    /// Unlike repeated calls to [`Self::edit_role_position`], the reordering is applied
+    /// atomically. Returns all of the guild's roles with their updated positions.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission. Only roles below the current user's
+    /// highest role can be moved, and they can't be moved above it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::{GuildId, RoleId};
+    /// # async fn run() -> serenity::Result<()> {
+    /// # let http: Http = unimplemented!();
+    /// let positions = [(RoleId::new(8), 2), (RoleId::new(9), 1)];
+    /// GuildId::new(7).edit_role_positions(&http, positions, Some("Reordering")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user lacks permission, or if a role would be
+    /// moved to or from a position above the current user's highest role.
+    ///
+    /// [Manage Roles]: Permissions::MANAGE_ROLES
+    pub async fn edit_role_positions(
+        self,
+        http: impl AsRef<Http>,
+        positions: impl IntoIterator<Item = (RoleId, u16)>,
+        reason: Option<&str>,
+    ) -> Result<Vec<Role>> {
+        let items = positions
+            .into_iter()
+            .map(|(id, position)| {
+                json!({
+                    "id": id,
+                    "position": position,
+                })
+            })
+            .collect::<Vec<_>>()
+            .into();
+
+        http.as_ref().edit_role_positions(self, &items, reason).await
+    }
+
+    /// Gets the guild's discovery metadata, such as its discovery category and search keywords.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission, and the guild must have the
+    /// `DISCOVERABLE` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn discovery_metadata(
+        self,
+        http: impl AsRef<Http>,
+    ) -> Result<GuildDiscoveryMetadata> {
+        http.as_ref().get_guild_discovery_metadata(self).await
+    }
+
+    /// Edits the guild's discovery metadata.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission, and the guild must have the
+    /// `DISCOVERABLE` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn edit_discovery_metadata(
+        self,
+        cache_http: impl CacheHttp,
+        builder: EditGuildDiscoveryMetadata<'_>,
+    ) -> Result<GuildDiscoveryMetadata> {
+        builder.execute(cache_http, self).await
+    }
+
     /// Edits the guild's welcome screen.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
@@ -881,6 +1168,25 @@ impl GuildId {
         builder.execute(cache_http, self).await
     }
 
+    /// Edits the guild's onboarding flow.
+    ///
+    /// **Note**: Requires the [Manage Guild] and [Manage Roles] permissions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the resulting flow
+    /// does not satisfy Discord's onboarding constraints.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    /// [Manage Roles]: Permissions::MANAGE_ROLES
+    pub async fn edit_onboarding(
+        self,
+        cache_http: impl CacheHttp,
+        builder: EditGuildOnboarding<'_>,
+    ) -> Result<GuildOnboarding> {
+        builder.execute(cache_http, self).await
+    }
+
     /// Edits the guild's widget.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
@@ -992,6 +1298,30 @@ impl GuildId {
         http.as_ref().get_emoji(self, emoji_id).await
     }
 
+    /// Gets all [`SoundboardSound`]s of this guild via HTTP.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the guild is unavailable.
+    #[inline]
+    pub async fn soundboard_sounds(self, http: impl AsRef<Http>) -> Result<Vec<SoundboardSound>> {
+        http.as_ref().get_guild_soundboard_sounds(self).await
+    }
+
+    /// Gets a [`SoundboardSound`] of this guild by its ID via HTTP.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if a sound with that Id does not exist.
+    #[inline]
+    pub async fn soundboard_sound(
+        self,
+        http: impl AsRef<Http>,
+        sound_id: SoundId,
+    ) -> Result<SoundboardSound> {
+        http.as_ref().get_guild_soundboard_sound(self, sound_id).await
+    }
+
     /// Gets all [`Sticker`]s of this guild via HTTP.
     ///
     /// # Errors
@@ -1012,6 +1342,79 @@ impl GuildId {
         http.as_ref().get_guild_sticker(self, sticker_id).await
     }
 
+    /// Gets all [`GuildTemplate`]s of this guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn templates(self, http: impl AsRef<Http>) -> Result<Vec<GuildTemplate>> {
+        http.as_ref().get_guild_templates(self).await
+    }
+
+    /// Creates a [`GuildTemplate`] from the guild's current state.
+    ///
+    /// The `name` must be between 1 and 100 characters long, and the `description` at most 120.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user lacks permission, or if invalid data is
+    /// given.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn create_template(
+        self,
+        http: impl AsRef<Http>,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<GuildTemplate> {
+        let map = json!({
+            "name": name,
+            "description": description,
+        });
+        http.as_ref().create_guild_template(self, &map).await
+    }
+
+    /// Syncs a [`GuildTemplate`] to the guild's current state.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user lacks permission, or if no template with
+    /// the given code exists for the guild.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn sync_template(self, http: impl AsRef<Http>, code: &str) -> Result<GuildTemplate> {
+        http.as_ref().sync_guild_template(self, code).await
+    }
+
+    /// Deletes a [`GuildTemplate`], returning the deleted template.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user lacks permission, or if no template with
+    /// the given code exists for the guild.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn delete_template(
+        self,
+        http: impl AsRef<Http>,
+        code: &str,
+    ) -> Result<GuildTemplate> {
+        http.as_ref().delete_guild_template(self, code).await
+    }
+
     /// Gets all integration of the guild.
     ///
     /// Requires the [Manage Guild] permission.
@@ -1224,6 +1627,109 @@ impl GuildId {
         self.edit_member(cache_http, user_id, EditMember::new().disconnect_member()).await
     }
 
+    /// Times out a member until the given time, preventing them from sending messages, reacting,
+    /// or joining voice channels.
+    ///
+    /// **Note**: Requires the [Moderate Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotInRange`] if `until` is not in the future, or is more than 28 days from
+    /// now.
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Moderate Members]: Permissions::MODERATE_MEMBERS
+    #[doc(alias = "disable_communication_until")]
+    pub async fn timeout_member(
+        self,
+        cache_http: impl CacheHttp,
+        user_id: impl Into<UserId>,
+        until: Timestamp,
+        reason: Option<&str>,
+    ) -> Result<Member> {
+        let secs = until.unix_timestamp() - Timestamp::now().unix_timestamp();
+        let max = crate::constants::TIMEOUT_MAX_SECS;
+        if secs <= 0 || secs as u64 > max {
+            return Err(Error::NotInRange("until", secs.max(0) as u64, 1, max));
+        }
+
+        let mut builder = EditMember::new().disable_communication_until_datetime(until);
+        if let Some(reason) = reason {
+            builder = builder.audit_log_reason(reason);
+        }
+
+        self.edit_member(cache_http, user_id, builder).await
+    }
+
+    /// Removes the timeout of a member, if they have one.
+    ///
+    /// **Note**: Requires the [Moderate Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Moderate Members]: Permissions::MODERATE_MEMBERS
+    #[doc(alias = "enable_communication")]
+    pub async fn remove_timeout(
+        self,
+        cache_http: impl CacheHttp,
+        user_id: impl Into<UserId>,
+        reason: Option<&str>,
+    ) -> Result<Member> {
+        let mut builder = EditMember::new().enable_communication();
+        if let Some(reason) = reason {
+            builder = builder.audit_log_reason(reason);
+        }
+
+        self.edit_member(cache_http, user_id, builder).await
+    }
+
+    /// Moves a member to the given voice or stage channel, or disconnects them from voice if
+    /// `channel_id` is [`None`].
+    ///
+    /// **Note**: Requires the [Move Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns [`ModelError::InvalidChannelType`] if the target channel
+    /// is cached and is neither a voice nor a stage channel.
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the member is not
+    /// currently in a voice channel for this guild.
+    ///
+    /// [Move Members]: Permissions::MOVE_MEMBERS
+    pub async fn move_member_to_voice(
+        self,
+        cache_http: impl CacheHttp,
+        user_id: impl Into<UserId>,
+        channel_id: Option<ChannelId>,
+        reason: Option<&str>,
+    ) -> Result<Member> {
+        #[cfg(feature = "cache")]
+        {
+            if let (Some(cache), Some(channel_id)) = (cache_http.cache(), channel_id) {
+                if let Some(guild) = cache.guild(self) {
+                    let is_cached = guild.channels.contains_key(&channel_id);
+                    if is_cached && !guild.is_voice_channel(channel_id) {
+                        return Err(Error::Model(ModelError::InvalidChannelType));
+                    }
+                }
+            }
+        }
+
+        let mut builder = match channel_id {
+            Some(channel_id) => EditMember::new().voice_channel(channel_id),
+            None => EditMember::new().disconnect_member(),
+        };
+        if let Some(reason) = reason {
+            builder = builder.audit_log_reason(reason);
+        }
+
+        self.edit_member(cache_http, user_id, builder).await
+    }
+
     /// Gets the number of [`Member`]s that would be pruned with the given number of days.
     ///
     /// Requires the [Kick Members] permission.
@@ -1280,14 +1786,21 @@ impl GuildId {
     ///
     /// # Errors
     ///
-    /// Returns an [`Error::Http`] if the API returns an error.
-    #[inline]
+    /// Returns [`Error::NotInRange`] if `limit` is not between 1 and 1000. Otherwise returns an
+    /// [`Error::Http`] if the API returns an error.
     pub async fn search_members(
         self,
         http: impl AsRef<Http>,
         query: &str,
         limit: Option<u64>,
     ) -> Result<Vec<Member>> {
+        if let Some(limit) = limit {
+            let max = crate::constants::MEMBER_FETCH_LIMIT;
+            if !(1..=max).contains(&limit) {
+                return Err(Error::NotInRange("limit", limit, 1, max));
+            }
+        }
+
         http.as_ref().search_guild_members(self, query, limit).await
     }
 
@@ -1452,6 +1965,45 @@ impl GuildId {
         http.as_ref().start_guild_prune(self, days, None).await
     }
 
+    /// Starts a prune of [`Member`]s that have been inactive for the given number of days.
+    ///
+    /// By default, members with any role are not pruned. Members whose only roles are among
+    /// `include_roles` are pruned as well.
+    ///
+    /// If `compute_count` is `false`, [`GuildPrune::pruned`] will be [`None`]. This is recommended
+    /// for large guilds.
+    ///
+    /// **Note**: Requires [Kick Members] and [Manage Guild] permissions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotInRange`] if `days` is not between 1 and 30.
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Kick Members]: Permissions::KICK_MEMBERS
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn start_prune_with_options(
+        self,
+        http: impl AsRef<Http>,
+        days: u8,
+        include_roles: &[RoleId],
+        compute_count: bool,
+        reason: Option<&str>,
+    ) -> Result<GuildPrune> {
+        if !(1..=30).contains(&days) {
+            return Err(Error::NotInRange("days", days.into(), 1, 30));
+        }
+
+        let map = json!({
+            "days": days,
+            "include_roles": include_roles,
+            "compute_prune_count": compute_count,
+        });
+
+        http.as_ref().start_guild_prune_with_options(self, &map, reason).await
+    }
+
     /// Unbans a [`User`] from the guild.
     ///
     /// **Note**: Requires the [Ban Members] permission.
@@ -1466,6 +2018,20 @@ impl GuildId {
         http.as_ref().remove_ban(self, user_id.into(), None).await
     }
 
+    /// # Errors
+    ///
+    /// In addition to the reasons [`Self::unban`] may return an error, may also return an error if
+    /// the reason is too long.
+    #[inline]
+    pub async fn unban_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        user_id: impl Into<UserId>,
+        reason: &str,
+    ) -> Result<()> {
+        http.as_ref().remove_ban(self, user_id.into(), Some(reason)).await
+    }
+
     /// Retrieve's the guild's vanity URL.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
@@ -1481,20 +2047,36 @@ impl GuildId {
         http.as_ref().get_guild_vanity_url(self).await
     }
 
-    /// Retrieves the guild's webhooks.
+    /// Retrieves the guild's vanity invite code along with its number of uses.
     ///
-    /// **Note**: Requires the [Manage Webhooks] permission.
+    /// **Note**: Requires the [Manage Guild] permission, and the guild must have the `VANITY_URL`
+    /// feature.
     ///
-    /// [Manage Webhooks]: Permissions::MANAGE_WEBHOOKS
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn vanity_invite(self, http: impl AsRef<Http>) -> Result<VanityInviteInfo> {
+        http.as_ref().get_guild_vanity_invite(self).await
+    }
+
+    /// Retrieves the webhooks of every channel in the guild. To only list the webhooks of a single
+    /// channel, use [`ChannelId::webhooks`].
+    ///
+    /// **Note**: Requires the [Manage Webhooks] permission.
     ///
     /// # Errors
     ///
     /// Will return an [`Error::Http`] if the bot is lacking permissions. Can also return an
     /// [`Error::Json`] if there is an error deserializing the API response.
+    ///
+    /// [Manage Webhooks]: Permissions::MANAGE_WEBHOOKS
     #[inline]
     pub async fn webhooks(self, http: impl AsRef<Http>) -> Result<Vec<Webhook>> {
         http.as_ref().get_guild_webhooks(self).await
     }
+
     /// Returns a builder which can be awaited to obtain a message or stream of messages in this
     /// guild.
     #[cfg(feature = "collector")]
@@ -1650,6 +2232,15 @@ impl GuildId {
         http.as_ref().get_guild_command_permissions(self, command_id).await
     }
 
+    /// Gets the guild's onboarding flow.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user is not in the guild.
+    pub async fn onboarding(self, http: impl AsRef<Http>) -> Result<GuildOnboarding> {
+        http.as_ref().get_guild_onboarding(self).await
+    }
+
     /// Get the guild welcome screen.
     ///
     /// # Errors
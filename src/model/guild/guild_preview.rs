@@ -1,7 +1,11 @@
 use crate::model::guild::Emoji;
 use crate::model::id::GuildId;
+#[cfg(feature = "model")]
+use crate::model::misc::ImageFormat;
 use crate::model::misc::ImageHash;
 use crate::model::sticker::Sticker;
+#[cfg(feature = "model")]
+use crate::model::utils::discovery_splash_url;
 
 /// Preview [`Guild`] information.
 ///
@@ -36,3 +40,26 @@ pub struct GuildPreview {
     /// Custom guild stickers.
     pub stickers: Vec<Sticker>,
 }
+
+#[cfg(feature = "model")]
+impl GuildPreview {
+    /// Returns the formatted URL of the guild's discovery splash image, if one exists.
+    #[must_use]
+    pub fn discovery_splash_url(&self) -> Option<String> {
+        discovery_splash_url(self.id, self.discovery_splash.as_ref(), Some(ImageFormat::WebP), 4096)
+    }
+
+    /// Returns the formatted URL of the guild's discovery splash image in the given format and
+    /// size, if one exists.
+    ///
+    /// If no format is given, this behaves like [`Self::discovery_splash_url`]. See
+    /// [`ImageFormat`] for how the size is rounded.
+    #[must_use]
+    pub fn discovery_splash_url_sized(
+        &self,
+        format: Option<ImageFormat>,
+        size: u16,
+    ) -> Option<String> {
+        discovery_splash_url(self.id, self.discovery_splash.as_ref(), format, size)
+    }
+}
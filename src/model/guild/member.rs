@@ -1,6 +1,8 @@
 #[cfg(feature = "cache")]
 use std::cmp::Reverse;
 use std::fmt;
+#[cfg(feature = "model")]
+use std::time::Duration;
 
 #[cfg(feature = "model")]
 use crate::builder::EditMember;
@@ -248,6 +250,83 @@ impl Member {
         }
     }
 
+    /// Returns since when the member is boosting the guild, or [`None`] if they aren't boosting
+    /// it.
+    #[inline]
+    #[must_use]
+    pub fn boosting_since(&self) -> Option<Timestamp> {
+        self.premium_since
+    }
+
+    /// Calculates for how long the member has been boosting the guild, or [`None`] if they aren't
+    /// boosting it.
+    ///
+    /// If [`Self::premium_since`] lies in the future, e.g. due to clock skew, a zero duration is
+    /// returned.
+    #[must_use]
+    pub fn boost_duration(&self) -> Option<Duration> {
+        let since = self.premium_since?;
+        let secs = Timestamp::now().unix_timestamp().saturating_sub(since.unix_timestamp());
+
+        Some(Duration::from_secs(u64::try_from(secs).unwrap_or(0)))
+    }
+
+    /// Whether the member has left and rejoined the guild.
+    ///
+    /// Shorthand for checking [`GuildMemberFlags::DID_REJOIN`].
+    #[inline]
+    #[must_use]
+    pub fn did_rejoin(&self) -> bool {
+        self.flags.contains(GuildMemberFlags::DID_REJOIN)
+    }
+
+    /// Whether the member has completed onboarding.
+    ///
+    /// Shorthand for checking [`GuildMemberFlags::COMPLETED_ONBOARDING`].
+    #[inline]
+    #[must_use]
+    pub fn completed_onboarding(&self) -> bool {
+        self.flags.contains(GuildMemberFlags::COMPLETED_ONBOARDING)
+    }
+
+    /// Whether the member is exempt from the guild's verification requirements.
+    ///
+    /// Shorthand for checking [`GuildMemberFlags::BYPASSES_VERIFICATION`].
+    #[inline]
+    #[must_use]
+    pub fn bypasses_verification(&self) -> bool {
+        self.flags.contains(GuildMemberFlags::BYPASSES_VERIFICATION)
+    }
+
+    /// Whether the member has started onboarding.
+    ///
+    /// Shorthand for checking [`GuildMemberFlags::STARTED_ONBOARDING`].
+    #[inline]
+    #[must_use]
+    pub fn started_onboarding(&self) -> bool {
+        self.flags.contains(GuildMemberFlags::STARTED_ONBOARDING)
+    }
+
+    /// Whether the member hasn't passed the guild's membership screening yet.
+    ///
+    /// Shorthand for [`Self::pending`].
+    #[inline]
+    #[must_use]
+    pub fn is_pending(&self) -> bool {
+        self.pending
+    }
+
+    /// Creates a [`Mention`] of the member's user, without requiring [`Mentionable`] to be in
+    /// scope.
+    ///
+    /// Discord no longer distinguishes nickname mentions (`<@!USER_ID>`), so this is always in the
+    /// format of `<@USER_ID>`.
+    #[inline]
+    #[must_use]
+    pub fn mention(&self) -> Mention {
+        self.user.id.mention()
+    }
+
     /// Edits the member in place with the given data.
     ///
     /// See [`EditMember`] for the permission(s) required for separate builder methods, as well as
@@ -255,7 +334,20 @@ impl Member {
     ///
     /// # Examples
     ///
-    /// See [`GuildId::edit_member`] for details.
+    /// Change a member's nickname and mute them:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::builder::EditMember;
+    /// # use serenity::http::Http;
+    /// # use serenity::model::guild::Member;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http: Http = unimplemented!();
+    /// # let mut member: Member = unimplemented!();
+    /// let builder = EditMember::new().nickname("Muted").mute(true);
+    /// member.edit(&http, builder).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     ///
     /// # Errors
     ///
@@ -403,7 +495,8 @@ impl Member {
         self.guild_id.move_member(cache_http, self.user.id, channel).await
     }
 
-    /// Disconnects the member from their voice channel if any.
+    /// Disconnects the member from their voice channel if any, optionally with an audit log
+    /// reason.
     ///
     /// Requires the [Move Members] permission.
     ///
@@ -413,8 +506,13 @@ impl Member {
     /// current user lacks permission.
     ///
     /// [Move Members]: Permissions::MOVE_MEMBERS
-    pub async fn disconnect_from_voice(&self, cache_http: impl CacheHttp) -> Result<Member> {
-        self.guild_id.disconnect_member(cache_http, self.user.id).await
+    pub async fn disconnect_from_voice(
+        &self,
+        cache_http: impl CacheHttp,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        self.guild_id.move_member_to_voice(cache_http, self.user.id, None, reason).await?;
+        Ok(())
     }
 
     /// Returns the guild-level permissions for the member.
@@ -443,6 +541,37 @@ impl Member {
         Ok(guild.member_permissions(self))
     }
 
+    /// Calculates the member's guild-level permissions from their roles in the given guild.
+    ///
+    /// This does not take any channel's permission overwrites into account, so it is only suited
+    /// for checking permissions that apply to the whole guild, such as [Ban Members]. Use
+    /// [`Guild::user_permissions_in`] for permissions in a specific channel.
+    ///
+    /// **Note**: The guild must be the one the member is in, as its roles are looked up there.
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    #[must_use]
+    pub fn guild_permissions(&self, guild: &Guild) -> Permissions {
+        Guild::user_permissions_in_(
+            None,
+            self.user.id,
+            &self.roles,
+            guild.id,
+            &guild.roles,
+            guild.owner_id,
+        )
+    }
+
+    /// Returns the position and Id of the member's highest role in the given guild, which is the
+    /// key used to compare members in the role hierarchy.
+    ///
+    /// Returns [`None`] if the member has none of the guild's roles. See
+    /// [`Guild::member_highest_role`] to obtain the [`Role`] itself.
+    #[must_use]
+    pub fn highest_role_position(&self, guild: &Guild) -> Option<(u16, RoleId)> {
+        guild.member_highest_role(self).map(|role| (role.position, role.id))
+    }
+
     /// Removes a [`Role`] from the member.
     ///
     /// **Note**: Requires the [Manage Roles] permission.
@@ -519,7 +648,18 @@ impl Member {
     #[inline]
     #[must_use]
     pub fn avatar_url(&self) -> Option<String> {
-        avatar_url(Some(self.guild_id), self.user.id, self.avatar.as_ref())
+        avatar_url(Some(self.guild_id), self.user.id, self.avatar.as_ref(), None, 1024)
+    }
+
+    /// Returns the formatted URL of the member's per guild avatar in the given format and size, if
+    /// one exists.
+    ///
+    /// If no format is given, this behaves like [`Self::avatar_url`]. See [`ImageFormat`] for
+    /// how the size is rounded.
+    #[inline]
+    #[must_use]
+    pub fn avatar_url_sized(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        avatar_url(Some(self.guild_id), self.user.id, self.avatar.as_ref(), format, size)
     }
 
     /// Retrieves the URL to the current member's avatar, falling back to the user's avatar, then
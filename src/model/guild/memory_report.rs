@@ -0,0 +1,145 @@
+use std::fmt;
+
+use typesize::TypeSize;
+
+use super::Guild;
+
+/// The memory used by a single collection of a [`Guild`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct CollectionMemory {
+    /// The number of entries in the collection.
+    pub len: usize,
+    /// The number of bytes allocated on the heap by the collection and its entries.
+    pub heap_bytes: usize,
+}
+
+impl CollectionMemory {
+    fn of<T: TypeSize>(collection: &T, len: usize) -> Self {
+        Self {
+            len,
+            heap_bytes: collection.extra_size(),
+        }
+    }
+}
+
+/// A breakdown of the memory used by a [`Guild`], per collection.
+///
+/// Created by [`Guild::memory_report`]. The [`Display`] implementation renders the report as a
+/// human-readable table.
+///
+/// [`Display`]: fmt::Display
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct GuildMemoryReport {
+    /// The total number of bytes used by the guild, including its inline size.
+    pub total_bytes: usize,
+    /// The memory used by [`Guild::members`].
+    pub members: CollectionMemory,
+    /// The memory used by [`Guild::channels`].
+    pub channels: CollectionMemory,
+    /// The memory used by [`Guild::threads`].
+    pub threads: CollectionMemory,
+    /// The memory used by [`Guild::roles`].
+    pub roles: CollectionMemory,
+    /// The memory used by [`Guild::emojis`].
+    pub emojis: CollectionMemory,
+    /// The memory used by [`Guild::stickers`].
+    pub stickers: CollectionMemory,
+    /// The memory used by [`Guild::presences`].
+    pub presences: CollectionMemory,
+    /// The memory used by [`Guild::voice_states`].
+    pub voice_states: CollectionMemory,
+    /// The memory used by [`Guild::stage_instances`].
+    pub stage_instances: CollectionMemory,
+    /// The memory used by [`Guild::scheduled_events`].
+    pub scheduled_events: CollectionMemory,
+}
+
+impl GuildMemoryReport {
+    fn collections(&self) -> [(&'static str, CollectionMemory); 10] {
+        [
+            ("members", self.members),
+            ("channels", self.channels),
+            ("threads", self.threads),
+            ("roles", self.roles),
+            ("emojis", self.emojis),
+            ("stickers", self.stickers),
+            ("presences", self.presences),
+            ("voice_states", self.voice_states),
+            ("stage_instances", self.stage_instances),
+            ("scheduled_events", self.scheduled_events),
+        ]
+    }
+
+    /// Returns the number of bytes not attributed to any of the reported collections, such as the
+    /// inline size of the guild and its remaining fields.
+    #[must_use]
+    pub fn other_bytes(&self) -> usize {
+        let collections: usize = self.collections().iter().map(|(_, c)| c.heap_bytes).sum();
+        self.total_bytes.saturating_sub(collections)
+    }
+}
+
+impl fmt::Display for GuildMemoryReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, collection) in self.collections() {
+            writeln!(
+                f,
+                "{name:<16} {:>8} entries {:>12} bytes",
+                collection.len, collection.heap_bytes
+            )?;
+        }
+
+        writeln!(f, "{:<16} {:>8}         {:>12} bytes", "other", "", self.other_bytes())?;
+        write!(f, "{:<16} {:>8}         {:>12} bytes", "total", "", self.total_bytes)
+    }
+}
+
+impl Guild {
+    /// Returns a breakdown of the memory used by this guild, per collection, for attributing
+    /// cache memory usage.
+    #[must_use]
+    pub fn memory_report(&self) -> GuildMemoryReport {
+        GuildMemoryReport {
+            total_bytes: self.get_size(),
+            members: CollectionMemory::of(&self.members, self.members.len()),
+            channels: CollectionMemory::of(&self.channels, self.channels.len()),
+            threads: CollectionMemory::of(&self.threads, self.threads.len()),
+            roles: CollectionMemory::of(&self.roles, self.roles.len()),
+            emojis: CollectionMemory::of(&self.emojis, self.emojis.len()),
+            stickers: CollectionMemory::of(&self.stickers, self.stickers.len()),
+            presences: CollectionMemory::of(&self.presences, self.presences.len()),
+            voice_states: CollectionMemory::of(&self.voice_states, self.voice_states.len()),
+            stage_instances: CollectionMemory::of(&self.stage_instances, self.stage_instances.len()),
+            scheduled_events: CollectionMemory::of(
+                &self.scheduled_events,
+                self.scheduled_events.len(),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::prelude::*;
+
+    #[test]
+    fn memory_report() {
+        let mut guild = Guild::default();
+        let role = Role {
+            id: RoleId::new(1),
+            name: "a role with a name long enough to be heap allocated".into(),
+            ..Default::default()
+        };
+        guild.roles.insert(role.id, role);
+
+        let report = guild.memory_report();
+        assert_eq!(report.roles.len, 1);
+        assert!(report.roles.heap_bytes > 0);
+        assert_eq!(report.members, CollectionMemory::default());
+        assert!(report.total_bytes >= report.roles.heap_bytes + report.other_bytes());
+        assert_eq!(report.to_string().lines().count(), 12);
+    }
+}
@@ -2,50 +2,74 @@
 
 pub mod audit_log;
 pub mod automod;
+mod discovery_metadata;
 mod emoji;
+mod guild_builder;
 mod guild_id;
 mod guild_preview;
 mod integration;
 mod member;
+#[cfg(feature = "typesize")]
+mod memory_report;
+mod onboarding;
 mod partial_guild;
 mod premium_tier;
 mod role;
 mod scheduled_event;
 mod system_channel;
+mod template;
 mod welcome_screen;
 
 #[cfg(feature = "model")]
 use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::fmt;
+use std::str::FromStr;
 
+use serde::Serialize;
 #[cfg(feature = "model")]
 use tracing::{error, warn};
 
+pub use self::discovery_metadata::*;
 pub use self::emoji::*;
+pub use self::guild_builder::*;
 pub use self::guild_id::*;
 pub use self::guild_preview::*;
 pub use self::integration::*;
 pub use self::member::*;
+#[cfg(feature = "typesize")]
+pub use self::memory_report::*;
+pub use self::onboarding::*;
 pub use self::partial_guild::*;
 pub use self::premium_tier::*;
 pub use self::role::*;
 pub use self::scheduled_event::*;
 pub use self::system_channel::*;
+pub use self::template::*;
 pub use self::welcome_screen::*;
 #[cfg(feature = "model")]
 use crate::builder::{
     AddMember,
+    AuditLogFilter,
+    Builder,
+    CreateAttachment,
     CreateChannel,
     CreateCommand,
+    CreateGuild,
     CreateScheduledEvent,
+    CreateSoundboardSound,
     CreateSticker,
     EditAutoModRule,
     EditCommandPermissions,
     EditGuild,
+    EditGuildDiscoveryMetadata,
+    EditGuildOnboarding,
     EditGuildWelcomeScreen,
     EditGuildWidget,
     EditMember,
     EditRole,
     EditScheduledEvent,
+    EditSoundboardSound,
     EditSticker,
 };
 #[cfg(all(feature = "cache", feature = "model"))]
@@ -58,8 +82,6 @@ use crate::constants::LARGE_THRESHOLD;
 use crate::gateway::ShardMessenger;
 #[cfg(feature = "model")]
 use crate::http::{CacheHttp, Http, UserPagination};
-#[cfg(feature = "model")]
-use crate::json::json;
 use crate::model::prelude::*;
 use crate::model::utils::*;
 
@@ -104,6 +126,7 @@ pub struct AfkMetadata {
 /// [extension](https://discord.com/developers/docs/topics/gateway-events#guild-create).
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 #[non_exhaustive]
 pub struct Guild {
     /// The unique Id identifying the guild.
@@ -147,6 +170,10 @@ pub struct Guild {
     /// Default explicit content filter level.
     pub explicit_content_filter: ExplicitContentFilter,
     /// A mapping of the guild's roles.
+    ///
+    /// **Note**: The order returned by [`Self::roles_sorted_cached`] is kept up to date by the
+    /// cache and the guild's own methods. If this map is modified directly,
+    /// [`Self::refresh_sorted_role_ids`] must be called afterwards, otherwise that order is stale.
     #[serde(with = "roles")]
     pub roles: HashMap<RoleId, Role>,
     /// All of the guild's custom emojis.
@@ -269,6 +296,9 @@ pub struct Guild {
     ///
     /// This contains all channels regardless of permissions (i.e. the ability of the bot to read
     /// from or connect to them).
+    ///
+    /// **Note**: The iteration order of this map is unspecified, and does not match the order of
+    /// the channels in the client.
     #[serde(serialize_with = "serialize_map_values")]
     #[serde(deserialize_with = "deserialize_guild_channels")]
     pub channels: HashMap<ChannelId, GuildChannel>,
@@ -288,6 +318,25 @@ pub struct Guild {
     /// The stage instances in this guild.
     #[serde(rename = "guild_scheduled_events")]
     pub scheduled_events: Vec<ScheduledEvent>,
+    /// The Ids of [`Self::roles`] in the order of [`Self::roles_sorted`].
+    #[serde(skip)]
+    #[cfg_attr(feature = "typesize", typesize(skip))]
+    pub(crate) sorted_role_ids: Vec<RoleId>,
+}
+
+// Manual impl needed to fill in the sorted role index, which is not part of the payload
+impl<'de> Deserialize<'de> for Guild {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let mut guild = Self::deserialize(deserializer)?; // calls #[serde(remote)]-generated inherent method
+        guild.refresh_sorted_role_ids();
+        Ok(guild)
+    }
+}
+
+impl Serialize for Guild {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        Self::serialize(self, serializer) // calls #[serde(remote)]-generated inherent method
+    }
 }
 
 #[cfg(feature = "model")]
@@ -397,31 +446,64 @@ impl Guild {
         Ok(())
     }
 
+    /// Whether the channel with the given Id is a voice or stage channel of this guild.
+    ///
+    /// Returns `false` if the channel is not among [`Self::channels`].
+    #[must_use]
+    pub fn is_voice_channel(&self, channel_id: ChannelId) -> bool {
+        self.channels
+            .get(&channel_id)
+            .is_some_and(|channel| matches!(channel.kind, ChannelType::Voice | ChannelType::Stage))
+    }
+
+    /// Returns the Id and [region override] of every voice and stage channel of this guild.
+    ///
+    /// A region of [`None`] means that the region is selected automatically.
+    ///
+    /// [region override]: GuildChannel::rtc_region
+    pub fn voice_channel_regions(&self) -> impl Iterator<Item = (ChannelId, Option<&str>)> {
+        self.channels
+            .values()
+            .filter(|channel| matches!(channel.kind, ChannelType::Voice | ChannelType::Stage))
+            .map(|channel| (channel.id, channel.rtc_region.as_deref()))
+    }
+
     /// Returns the "default" channel of the guild for the passed user id. (This returns the first
     /// channel that can be read by the user, if there isn't one, returns [`None`])
+    ///
+    /// Channels are ordered by their position, with ties broken by their Id, so the result does
+    /// not depend on the iteration order of [`Self::channels`].
     #[must_use]
     pub fn default_channel(&self, uid: UserId) -> Option<&GuildChannel> {
         let member = self.members.get(&uid)?;
-        self.channels.values().find(|&channel| {
-            channel.kind != ChannelType::Category
-                && self.user_permissions_in(channel, member).view_channel()
-        })
+        self.channels
+            .values()
+            .filter(|&channel| {
+                channel.kind != ChannelType::Category
+                    && self.user_permissions_in(channel, member).view_channel()
+            })
+            .min_by_key(|channel| (channel.position, channel.id))
     }
 
     /// Returns the guaranteed "default" channel of the guild. (This returns the first channel that
     /// can be read by everyone, if there isn't one, returns [`None`])
     ///
+    /// Channels are ordered the same way as in [`Self::default_channel`].
+    ///
     /// **Note**: This is very costly if used in a server with lots of channels, members, or both.
     #[must_use]
     pub fn default_channel_guaranteed(&self) -> Option<&GuildChannel> {
-        self.channels.values().find(|&channel| {
-            channel.kind != ChannelType::Category
-                && self
-                    .members
-                    .values()
-                    .map(|member| self.user_permissions_in(channel, member))
-                    .all(Permissions::view_channel)
-        })
+        self.channels
+            .values()
+            .filter(|&channel| {
+                channel.kind != ChannelType::Category
+                    && self
+                        .members
+                        .values()
+                        .map(|member| self.user_permissions_in(channel, member))
+                        .all(Permissions::view_channel)
+            })
+            .min_by_key(|channel| (channel.position, channel.id))
     }
 
     /// Intentionally not async. Retrieving anything from HTTP here is overkill/undesired
@@ -532,6 +614,42 @@ impl Guild {
         self.id.ban_with_reason(cache_http.http(), user, dmd, reason).await
     }
 
+    /// Ban a [`User`] from the guild, deleting the messages they sent in the last
+    /// `delete_message_seconds` seconds, up to 7 days.
+    ///
+    /// **Note**: Requires the [Ban Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
+    /// does not have permission to perform bans, or may return a [`ModelError::Hierarchy`] if the
+    /// member to be banned has a higher role than the current user.
+    ///
+    /// Otherwise, see [`GuildId::ban_with_message_deletion`].
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    pub async fn ban_with_message_deletion(
+        &self,
+        cache_http: impl CacheHttp,
+        user: impl Into<UserId>,
+        delete_message_seconds: u32,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let user = user.into();
+
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                self.require_perms(cache, Permissions::BAN_MEMBERS)?;
+
+                self.check_hierarchy(cache, user)?;
+            }
+        }
+
+        let http = cache_http.http();
+        self.id.ban_with_message_deletion(http, user, delete_message_seconds, reason).await
+    }
+
     /// Bans multiple users from the guild, returning the users that were and weren't banned.
     ///
     /// # Errors
@@ -557,7 +675,16 @@ impl Guild {
     /// Returns the formatted URL of the guild's banner image, if one exists.
     #[must_use]
     pub fn banner_url(&self) -> Option<String> {
-        self.banner.as_ref().map(|banner| cdn!("/banners/{}/{}.webp?size=1024", self.id, banner))
+        banner_url(self.id, self.banner.as_deref(), None, 1024)
+    }
+
+    /// Returns the formatted URL of the guild's banner in the given format and size, if one exists.
+    ///
+    /// If no format is given, this behaves like [`Self::banner_url`]. See [`ImageFormat`] for
+    /// how the size is rounded.
+    #[must_use]
+    pub fn banner_url_sized(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        banner_url(self.id, self.banner.as_deref(), format, size)
     }
 
     /// Gets a list of the guild's bans, with additional options and filtering. See
@@ -592,9 +719,14 @@ impl Guild {
     /// Returns the created [`Member`] object, or nothing if the user is already a member of the
     /// guild.
     ///
+    /// **Note**: The access token must have been granted the `guilds.join` scope, and the current
+    /// user must be in the guild with the [Create Instant Invite] permission.
+    ///
     /// # Errors
     ///
     /// Returns [`Error::Http`] if the current user lacks permission, or if invalid data is given.
+    ///
+    /// [Create Instant Invite]: Permissions::CREATE_INSTANT_INVITE
     #[inline]
     pub async fn add_member(
         &self,
@@ -627,6 +759,49 @@ impl Guild {
         self.id.audit_logs(http, action_type, user_id, before, limit).await
     }
 
+    /// Gets a list of the guild's audit log entries matching the given filter.
+    ///
+    /// Refer to the documentation for [`AuditLogFilter`] for the available filters.
+    ///
+    /// **Note**: Requires the [View Audit Log] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [View Audit Log]: Permissions::VIEW_AUDIT_LOG
+    #[inline]
+    pub async fn audit_log_entries(
+        &self,
+        http: impl AsRef<Http>,
+        filter: AuditLogFilter,
+    ) -> Result<AuditLogs> {
+        self.id.audit_log_entries(http, filter).await
+    }
+
+    /// Finds the most recent audit log entry of the given action that targets `target_id` and was
+    /// created within the last `within`.
+    ///
+    /// Refer to [`GuildId::find_audit_entry`] for more information.
+    ///
+    /// **Note**: Requires the [View Audit Log] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [View Audit Log]: Permissions::VIEW_AUDIT_LOG
+    #[inline]
+    pub async fn find_audit_entry(
+        &self,
+        http: impl AsRef<Http>,
+        action: audit_log::Action,
+        target_id: impl Into<GenericId>,
+        within: std::time::Duration,
+    ) -> Result<Option<AuditLogEntry>> {
+        self.id.find_audit_entry(http, action, target_id, within).await
+    }
+
     /// Gets all of the guild's channels over the REST API.
     ///
     /// # Errors
@@ -645,18 +820,62 @@ impl Guild {
     /// Only a [`PartialGuild`] will be immediately returned, and a full [`Guild`] will be received
     /// over a [`Shard`].
     ///
-    /// **Note**: This endpoint is usually only available for user accounts. Refer to Discord's
-    /// information for the endpoint [here][whitelist] for more information. If you require this as
-    /// a bot, re-think what you are doing and if it _really_ needs to be doing this.
+    /// **Note**: This endpoint can only be used by bots in less than 10 guilds. Refer to Discord's
+    /// information for the endpoint [here][whitelist] for more information.
     ///
     /// # Examples
     ///
-    /// Create a guild called `"test"` in the [US West region] with no icon:
+    /// Create a guild called `"test"` with a moderator role and a category containing a channel
+    /// only visible to moderators. The Ids are placeholders, used to refer to the role and the
+    /// category within the request:
     ///
-    /// ```rust,ignore
-    /// use serenity::model::Guild;
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// use serenity::builder::{
+    ///     CreateChannel,
+    ///     CreateGuild,
+    ///     CreateGuildChannel,
+    ///     CreateGuildRole,
+    ///     EditRole,
+    /// };
+    /// use serenity::model::prelude::*;
     ///
-    /// let _guild = Guild::create_guild(&http, "test", None).await;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http: Http = unimplemented!();
+    /// let (everyone, moderator) = (RoleId::new(1), RoleId::new(2));
+    /// let category = ChannelId::new(3);
+    /// let overwrites = [
+    ///     PermissionOverwrite {
+    ///         allow: Permissions::empty(),
+    ///         deny: Permissions::VIEW_CHANNEL,
+    ///         kind: PermissionOverwriteType::Role(everyone),
+    ///     },
+    ///     PermissionOverwrite {
+    ///         allow: Permissions::VIEW_CHANNEL,
+    ///         deny: Permissions::empty(),
+    ///         kind: PermissionOverwriteType::Role(moderator),
+    ///     },
+    /// ];
+    ///
+    /// let builder = CreateGuild::new("test")
+    ///     .verification_level(VerificationLevel::Medium)
+    ///     .roles([
+    ///         CreateGuildRole::new(everyone, EditRole::new()),
+    ///         CreateGuildRole::new(moderator, EditRole::new().name("moderator")),
+    ///     ])
+    ///     .channels([
+    ///         CreateGuildChannel::new(
+    ///             category,
+    ///             CreateChannel::new("staff").kind(ChannelType::Category),
+    ///         ),
+    ///         CreateGuildChannel::new(
+    ///             ChannelId::new(4),
+    ///             CreateChannel::new("mod-chat").category(category).permissions(overwrites),
+    ///         ),
+    ///     ]);
+    /// let _guild = Guild::create(&http, builder).await?;
+    /// # Ok(())
+    /// # }
     /// ```
     ///
     /// # Errors
@@ -666,19 +885,13 @@ impl Guild {
     /// [`Shard`]: crate::gateway::Shard
     /// [whitelist]: https://discord.com/developers/docs/resources/guild#create-guild
     pub async fn create(
-        http: impl AsRef<Http>,
-        name: &str,
-        icon: Option<ImageHash>,
+        cache_http: impl CacheHttp,
+        builder: CreateGuild<'_>,
     ) -> Result<PartialGuild> {
-        let map = json!({
-            "icon": icon,
-            "name": name,
-        });
-
-        http.as_ref().create_guild(&map).await
+        builder.execute(cache_http, ()).await
     }
 
-    /// Creates a new [`Channel`] in the guild.
+    /// Creates a new [`Channel`] in the guild, and adds it to [`Self::channels`].
     ///
     /// **Note**: Requires the [Manage Channels] permission.
     ///
@@ -693,7 +906,7 @@ impl Guild {
     ///
     /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
     /// # let http: Http = unimplemented!();
-    /// # let guild = Guild::get(&http, GuildId::new(7)).await?;
+    /// # let mut guild: Guild = unimplemented!();
     /// let builder = CreateChannel::new("my-test-channel").kind(ChannelType::Text);
     ///
     /// // assuming a `guild` has already been bound
@@ -704,48 +917,56 @@ impl Guild {
     ///
     /// # Errors
     ///
+    /// Returns [`ModelError::NestedCategory`] if a category channel is given a parent category.
+    ///
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
     /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
     ///
     /// [Manage Channels]: Permissions::MANAGE_CHANNELS
     pub async fn create_channel(
-        &self,
+        &mut self,
         cache_http: impl CacheHttp,
         builder: CreateChannel<'_>,
     ) -> Result<GuildChannel> {
-        self.id.create_channel(cache_http, builder).await
+        let channel = self.id.create_channel(cache_http, builder).await?;
+        self.channels.insert(channel.id, channel.clone());
+
+        Ok(channel)
     }
 
-    /// Creates an emoji in the guild with a name and base64-encoded image. The
-    /// [`CreateAttachment`] builder is provided for you as a simple method to read an image and
-    /// encode it into base64, if you are reading from the filesystem.
+    /// Creates an emoji in the guild with a name and image, optionally restricted to the given
+    /// roles, and adds it to [`Self::emojis`].
     ///
-    /// The name of the emoji must be at least 2 characters long and can only contain alphanumeric
-    /// characters and underscores.
-    ///
-    /// Requires the [Create Guild Expressions] permission.
+    /// The name of the emoji must be between 2 and 32 characters long and can only contain
+    /// alphanumeric characters and underscores.
     ///
-    /// # Examples
+    /// The image may be a PNG, JPEG, GIF or WEBP file, detected from its contents. The emoji is
+    /// considered animated if the image is a GIF, an APNG or an animated WEBP. Static and animated
+    /// emojis have separate slots, see [`Self::emoji_slots`].
     ///
-    /// See the [`EditProfile::avatar`] example for an in-depth example as to how to read an image
-    /// from the filesystem and encode it as base64. Most of the example can be applied similarly
-    /// for this method.
+    /// Requires the [Create Guild Expressions] permission.
     ///
     /// # Errors
     ///
+    /// Returns [`ModelError::InvalidEmojiName`] if the name is invalid, and
+    /// [`ModelError::EmojiSlotsFull`] if there are no slots left for the kind of emoji.
+    ///
     /// Returns [`Error::Http`] if the current user lacks permission.
     ///
-    /// [`EditProfile::avatar`]: crate::builder::EditProfile::avatar
-    /// [`CreateAttachment`]: crate::builder::CreateAttachment
     /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
-    #[inline]
     pub async fn create_emoji(
-        &self,
+        &mut self,
         http: impl AsRef<Http>,
         name: &str,
-        image: &str,
+        image: &CreateAttachment,
+        roles: &[RoleId],
     ) -> Result<Emoji> {
-        self.id.create_emoji(http, name, image).await
+        let mime = self.check_emoji_slots(&image.data)?;
+        let encoded = image.to_base64_with_mime(mime);
+        let emoji = self.id.create_emoji_(http.as_ref(), name, &encoded, roles).await?;
+        self.emojis.insert(emoji.id, emoji.clone());
+
+        Ok(emoji)
     }
 
     /// Creates an integration for the guild.
@@ -904,7 +1125,11 @@ impl Guild {
         self.id.get_command_permissions(http, command_id).await
     }
 
-    /// Creates a new role in the guild with the data set, if any.
+    /// Creates a new role in the guild with the data set, if any, and inserts it into
+    /// [`Self::roles`].
+    ///
+    /// The `reason` is recorded in the audit log, taking precedence over one set through
+    /// [`EditRole::audit_log_reason`].
     ///
     /// **Note**: Requires the [Manage Roles] permission.
     ///
@@ -918,12 +1143,22 @@ impl Guild {
     /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
     ///
     /// [Manage Roles]: Permissions::MANAGE_ROLES
-    pub async fn create_role(
-        &self,
+    pub async fn create_role<'a>(
+        &mut self,
         cache_http: impl CacheHttp,
-        builder: EditRole<'_>,
+        mut builder: EditRole<'a>,
+        reason: Option<&'a str>,
     ) -> Result<Role> {
-        self.id.create_role(cache_http, builder).await
+        if let Some(reason) = reason {
+            builder = builder.audit_log_reason(reason);
+        }
+
+        let mut role = self.id.create_role(cache_http, builder).await?;
+        role.guild_id = self.id;
+        self.roles.insert(role.id, role.clone());
+        self.refresh_sorted_role_ids();
+
+        Ok(role)
     }
 
     /// Creates a new scheduled event in the guild with the data set, if any.
@@ -932,6 +1167,9 @@ impl Guild {
     ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::InvalidScheduledEventField`] if a field required by the event type
+    /// is missing, or a field not allowed for it is set.
+    ///
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
     /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
     ///
@@ -944,6 +1182,24 @@ impl Guild {
         self.id.create_scheduled_event(cache_http, builder).await
     }
 
+    /// Creates a new soundboard sound in the guild with the data set.
+    ///
+    /// **Note**: Requires the [Create Guild Expressions] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
+    /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    pub async fn create_soundboard_sound(
+        &self,
+        cache_http: impl CacheHttp,
+        builder: CreateSoundboardSound<'_>,
+    ) -> Result<SoundboardSound> {
+        self.id.create_soundboard_sound(cache_http, builder).await
+    }
+
     /// Creates a new sticker in the guild with the data set, if any.
     ///
     /// **Note**: Requires the [Create Guild Expressions] permission.
@@ -977,7 +1233,7 @@ impl Guild {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
-                if self.owner_id != cache.current_user().id {
+                if !self.is_owned_by_current_user(cache) {
                     return Err(Error::Model(ModelError::InvalidUser));
                 }
             }
@@ -986,7 +1242,7 @@ impl Guild {
         self.id.delete(cache_http.http()).await
     }
 
-    /// Deletes an [`Emoji`] from the guild.
+    /// Deletes an [`Emoji`] from the guild, and removes it from [`Self::emojis`].
     ///
     /// **Note**: If the emoji was created by the current user, requires either the [Create Guild
     /// Expressions] or the [Manage Guild Expressions] permission. Otherwise, the [Manage Guild
@@ -999,13 +1255,16 @@ impl Guild {
     ///
     /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
     /// [Manage Guild Expressions]: Permissions::MANAGE_GUILD_EXPRESSIONS
-    #[inline]
     pub async fn delete_emoji(
-        &self,
+        &mut self,
         http: impl AsRef<Http>,
         emoji_id: impl Into<EmojiId>,
     ) -> Result<()> {
-        self.id.delete_emoji(http, emoji_id).await
+        let emoji_id = emoji_id.into();
+        self.id.delete_emoji(http, emoji_id).await?;
+        self.emojis.remove(&emoji_id);
+
+        Ok(())
     }
 
     /// Deletes an integration by Id from the guild.
@@ -1067,6 +1326,28 @@ impl Guild {
         self.id.delete_scheduled_event(http, event_id).await
     }
 
+    /// Deletes a [`SoundboardSound`] by Id from the guild.
+    ///
+    /// **Note**: If the sound was created by the current user, requires either the [Create Guild
+    /// Expressions] or the [Manage Guild Expressions] permission. Otherwise, the [Manage Guild
+    /// Expressions] permission is required.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if a sound with that id
+    /// does not exist.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    /// [Manage Guild Expressions]: Permissions::MANAGE_GUILD_EXPRESSIONS
+    #[inline]
+    pub async fn delete_soundboard_sound(
+        &self,
+        http: impl AsRef<Http>,
+        sound_id: impl Into<SoundId>,
+    ) -> Result<()> {
+        self.id.delete_soundboard_sound(http, sound_id).await
+    }
+
     /// Deletes a [`Sticker`] by Id from the guild.
     ///
     /// **Note**: If the sticker was created by the current user, requires either the [Create Guild
@@ -1131,6 +1412,7 @@ impl Guild {
         self.name = guild.name;
         self.owner_id = guild.owner_id;
         self.roles = guild.roles;
+        self.refresh_sorted_role_ids();
         self.splash = guild.splash;
         self.verification_level = guild.verification_level;
 
@@ -1185,20 +1467,35 @@ impl Guild {
         self.id.edit_member(cache_http, user_id, builder).await
     }
 
-    /// Edits the guild's MFA level. Returns the new level on success.
+    /// Edits the guild's MFA level, and updates [`Self::mfa_level`]. Returns the new level on
+    /// success.
     ///
-    /// Requires guild ownership.
+    /// **Note**: Requires the current user to be the owner of the guild.
     ///
     /// # Errors
     ///
-    /// Returns [`Error::Http`] if the current user lacks permission.
+    /// If the `cache` is enabled, then returns a [`ModelError::InvalidUser`] if the current user
+    /// is not the guild owner.
+    ///
+    /// Otherwise returns [`Error::Http`] if the current user is not the owner of the guild.
     pub async fn edit_mfa_level(
-        &self,
-        http: impl AsRef<Http>,
+        &mut self,
+        cache_http: impl CacheHttp,
         mfa_level: MfaLevel,
         audit_log_reason: Option<&str>,
     ) -> Result<MfaLevel> {
-        self.id.edit_mfa_level(http, mfa_level, audit_log_reason).await
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                if !self.is_owned_by_current_user(cache) {
+                    return Err(Error::Model(ModelError::InvalidUser));
+                }
+            }
+        }
+
+        let http = cache_http.http();
+        self.mfa_level = self.id.edit_mfa_level(http, mfa_level, audit_log_reason).await?;
+        Ok(self.mfa_level)
     }
 
     /// Edits the current user's nickname for the guild.
@@ -1219,6 +1516,7 @@ impl Guild {
         &self,
         cache_http: impl CacheHttp,
         new_nickname: Option<&str>,
+        audit_log_reason: Option<&str>,
     ) -> Result<()> {
         #[cfg(feature = "cache")]
         {
@@ -1227,7 +1525,7 @@ impl Guild {
             }
         }
 
-        self.id.edit_nickname(cache_http.http(), new_nickname).await
+        self.id.edit_nickname(cache_http.http(), new_nickname, audit_log_reason).await
     }
 
     /// Edits a role, optionally setting its fields.
@@ -1280,6 +1578,37 @@ impl Guild {
         self.id.edit_role_position(http, role_id, position).await
     }
 
+    /// Edits the positions of multiple [`Role`]s in a single request, updating the positions of
+    /// the roles stored in [`Self::roles`] from the response.
+    ///
+    /// Refer to [`GuildId::edit_role_positions`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user lacks permission, or if a role would be
+    /// moved to or from a position above the current user's highest role.
+    ///
+    /// [Manage Roles]: Permissions::MANAGE_ROLES
+    pub async fn edit_role_positions(
+        &mut self,
+        http: impl AsRef<Http>,
+        positions: impl IntoIterator<Item = (RoleId, u16)>,
+        reason: Option<&str>,
+    ) -> Result<Vec<Role>> {
+        let roles = self.id.edit_role_positions(http, positions, reason).await?;
+
+        for role in &roles {
+            if let Some(existing) = self.roles.get_mut(&role.id) {
+                existing.position = role.position;
+            }
+        }
+        self.refresh_sorted_role_ids();
+
+        Ok(roles)
+    }
+
     /// Modifies a scheduled event in the guild with the data set, if any.
     ///
     /// **Note**: If the event was created by the current user, requires either [Create Events] or
@@ -1301,6 +1630,48 @@ impl Guild {
         self.id.edit_scheduled_event(cache_http, event_id, builder).await
     }
 
+    /// Edits a soundboard sound.
+    ///
+    /// **Note**: If the sound was created by the current user, requires either the [Create Guild
+    /// Expressions] or the [Manage Guild Expressions] permission. Otherwise, the [Manage Guild
+    /// Expressions] permission is required.
+    ///
+    /// # Examples
+    ///
+    /// Lower the volume of a sound:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::guild::Guild;
+    /// # use serenity::model::id::GuildId;
+    /// use serenity::builder::EditSoundboardSound;
+    /// use serenity::model::id::SoundId;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http: Http = unimplemented!();
+    /// # let guild: Guild = unimplemented!();
+    /// let builder = EditSoundboardSound::new().volume(0.5);
+    /// guild.edit_soundboard_sound(&http, SoundId::new(7), builder).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if invalid data is given.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    /// [Manage Guild Expressions]: Permissions::MANAGE_GUILD_EXPRESSIONS
+    #[inline]
+    pub async fn edit_soundboard_sound(
+        &self,
+        cache_http: impl CacheHttp,
+        sound_id: impl Into<SoundId>,
+        builder: EditSoundboardSound<'_>,
+    ) -> Result<SoundboardSound> {
+        self.id.edit_soundboard_sound(cache_http, sound_id, builder).await
+    }
+
     /// Edits a sticker.
     ///
     /// **Note**: If the sticker was created by the current user, requires either the [Create Guild
@@ -1343,54 +1714,129 @@ impl Guild {
         self.id.edit_sticker(cache_http, sticker_id, builder).await
     }
 
-    /// Edits the guild's welcome screen.
+    /// Gets the guild's discovery metadata, such as its discovery category and search keywords.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
     ///
     /// # Errors
     ///
+    /// Returns [`ModelError::MissingGuildFeature`] if the guild does not have the `DISCOVERABLE`
+    /// feature.
+    ///
     /// Returns [`Error::Http`] if the current user lacks permission.
     ///
     /// [Manage Guild]: Permissions::MANAGE_GUILD
-    pub async fn edit_welcome_screen(
+    pub async fn discovery_metadata(
         &self,
-        cache_http: impl CacheHttp,
-        builder: EditGuildWelcomeScreen<'_>,
-    ) -> Result<GuildWelcomeScreen> {
-        self.id.edit_welcome_screen(cache_http, builder).await
+        http: impl AsRef<Http>,
+    ) -> Result<GuildDiscoveryMetadata> {
+        if !self.has_feature("DISCOVERABLE") {
+            return Err(Error::Model(ModelError::MissingGuildFeature("DISCOVERABLE")));
+        }
+
+        self.id.discovery_metadata(http).await
     }
 
-    /// Edits the guild's widget.
+    /// Edits the guild's discovery metadata.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
     ///
     /// # Errors
     ///
+    /// Returns [`ModelError::MissingGuildFeature`] if the guild does not have the `DISCOVERABLE`
+    /// feature.
+    ///
     /// Returns [`Error::Http`] if the current user lacks permission.
     ///
     /// [Manage Guild]: Permissions::MANAGE_GUILD
-    pub async fn edit_widget(
+    pub async fn edit_discovery_metadata(
         &self,
         cache_http: impl CacheHttp,
-        builder: EditGuildWidget<'_>,
-    ) -> Result<GuildWidget> {
-        self.id.edit_widget(cache_http, builder).await
+        builder: EditGuildDiscoveryMetadata<'_>,
+    ) -> Result<GuildDiscoveryMetadata> {
+        if !self.has_feature("DISCOVERABLE") {
+            return Err(Error::Model(ModelError::MissingGuildFeature("DISCOVERABLE")));
+        }
+
+        self.id.edit_discovery_metadata(cache_http, builder).await
     }
 
-    /// Gets a partial amount of guild data by its Id.
+    /// Edits the guild's welcome screen.
     ///
-    /// **Note**: This will not be a [`Guild`], as the REST API does not send all data with a guild
-    /// retrieval.
+    /// **Note**: Requires the [Manage Guild] permission.
     ///
     /// # Errors
     ///
-    /// Returns an [`Error::Http`] if the current user is not in the guild.
-    #[inline]
-    pub async fn get(
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn edit_welcome_screen(
+        &self,
         cache_http: impl CacheHttp,
-        guild_id: impl Into<GuildId>,
-    ) -> Result<PartialGuild> {
-        guild_id.into().to_partial_guild(cache_http).await
+        builder: EditGuildWelcomeScreen<'_>,
+    ) -> Result<GuildWelcomeScreen> {
+        self.id.edit_welcome_screen(cache_http, builder).await
+    }
+
+    /// Edits the guild's onboarding flow.
+    ///
+    /// **Note**: Requires the [Manage Guild] and [Manage Roles] permissions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the resulting flow
+    /// does not satisfy Discord's onboarding constraints.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    /// [Manage Roles]: Permissions::MANAGE_ROLES
+    pub async fn edit_onboarding(
+        &self,
+        cache_http: impl CacheHttp,
+        builder: EditGuildOnboarding<'_>,
+    ) -> Result<GuildOnboarding> {
+        self.id.edit_onboarding(cache_http, builder).await
+    }
+
+    /// Gets the guild's onboarding flow.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user is not in the guild.
+    pub async fn onboarding(&self, http: impl AsRef<Http>) -> Result<GuildOnboarding> {
+        self.id.onboarding(http).await
+    }
+
+    /// Edits the guild's widget.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn edit_widget(
+        &self,
+        cache_http: impl CacheHttp,
+        builder: EditGuildWidget<'_>,
+    ) -> Result<GuildWidget> {
+        self.id.edit_widget(cache_http, builder).await
+    }
+
+    /// Gets a partial amount of guild data by its Id.
+    ///
+    /// **Note**: This will not be a [`Guild`], as the REST API does not send all data with a guild
+    /// retrieval.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user is not in the guild.
+    #[inline]
+    pub async fn get(
+        cache_http: impl CacheHttp,
+        guild_id: impl Into<GuildId>,
+    ) -> Result<PartialGuild> {
+        guild_id.into().to_partial_guild(cache_http).await
     }
 
     /// Gets the highest role a [`Member`] of this Guild has.
@@ -1398,10 +1844,17 @@ impl Guild {
     /// Returns None if the member has no roles or the member from this guild.
     #[must_use]
     pub fn member_highest_role(&self, member: &Member) -> Option<&Role> {
+        Self::member_highest_role_(&self.roles, member)
+    }
+
+    pub(crate) fn member_highest_role_<'a>(
+        roles: &'a HashMap<RoleId, Role>,
+        member: &Member,
+    ) -> Option<&'a Role> {
         let mut highest: Option<&Role> = None;
 
         for role_id in &member.roles {
-            if let Some(role) = self.roles.get(role_id) {
+            if let Some(role) = roles.get(role_id) {
                 // Skip this role if this role in iteration has:
                 // - a position less than the recorded highest
                 // - a position equal to the recorded, but a higher ID
@@ -1420,6 +1873,40 @@ impl Guild {
         highest
     }
 
+    /// Returns the roles of this guild that the given [`Member`] is able to assign to others.
+    ///
+    /// These are the non-managed roles positioned strictly below the member's highest role. The
+    /// guild owner can assign every non-managed role. The `@everyone` role is never included.
+    ///
+    /// **Note**: This does not check whether the member has the [Manage Roles] permission.
+    ///
+    /// [Manage Roles]: Permissions::MANAGE_ROLES
+    pub fn roles_assignable_by<'a>(&'a self, actor: &Member) -> impl Iterator<Item = &'a Role> {
+        Self::roles_assignable_by_(&self.roles, self.id, self.owner_id, actor)
+    }
+
+    pub(crate) fn roles_assignable_by_<'a>(
+        roles: &'a HashMap<RoleId, Role>,
+        guild_id: GuildId,
+        owner_id: UserId,
+        actor: &Member,
+    ) -> impl Iterator<Item = &'a Role> {
+        let is_owner = actor.user.id == owner_id;
+        let highest = Self::member_highest_role_(roles, actor).map(|r| (r.position, r.id));
+        let everyone_id = guild_id.everyone_role();
+
+        roles.values().filter(move |role| {
+            if role.managed || role.id == everyone_id {
+                return false;
+            }
+
+            is_owner
+                || highest.is_some_and(|(position, id)| {
+                    role.position < position || (role.position == position && role.id > id)
+                })
+        })
+    }
+
     /// Returns which of two [`User`]s has a higher [`Member`] hierarchy.
     ///
     /// Hierarchy is essentially who has the [`Role`] with the highest [`position`].
@@ -1457,13 +1944,11 @@ impl Guild {
             return Some(rhs_id);
         }
 
-        let lhs = self
-            .member_highest_role(self.members.get(&lhs_id)?)
-            .map_or((RoleId::new(1), 0), |r| (r.id, r.position));
+        let lhs = self.members.get(&lhs_id)?.highest_role_position(self);
+        let lhs = lhs.map_or((RoleId::new(1), 0), |(position, id)| (id, position));
 
-        let rhs = self
-            .member_highest_role(self.members.get(&rhs_id)?)
-            .map_or((RoleId::new(1), 0), |r| (r.id, r.position));
+        let rhs = self.members.get(&rhs_id)?.highest_role_position(self);
+        let rhs = rhs.map_or((RoleId::new(1), 0), |(position, id)| (id, position));
 
         // If LHS and RHS both have no top position or have the same role ID, then no one wins.
         if (lhs.1 == 0 && rhs.1 == 0) || (lhs.0 == rhs.0) {
@@ -1491,12 +1976,80 @@ impl Guild {
         }
     }
 
+    /// Returns the hash of the guild's icon, falling back to [`Self::icon_hash`] if
+    /// [`Self::icon`] is not set, as is the case for guilds sourced from a template.
+    #[must_use]
+    pub fn effective_icon_hash(&self) -> Option<&ImageHash> {
+        self.icon.as_ref().or(self.icon_hash.as_ref())
+    }
+
     /// Returns the formatted URL of the guild's icon, if one exists.
     ///
     /// This will produce a WEBP image URL, or GIF if the guild has a GIF icon.
     #[must_use]
     pub fn icon_url(&self) -> Option<String> {
-        icon_url(self.id, self.icon.as_ref())
+        icon_url(self.id, self.effective_icon_hash(), None, None)
+    }
+
+    /// Returns the formatted URL of the guild's icon in the given format and size, if one exists.
+    ///
+    /// If no format is given, this behaves like [`Self::icon_url`]. See [`ImageFormat`] for
+    /// how the size is rounded.
+    #[must_use]
+    pub fn icon_url_sized(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        icon_url(self.id, self.effective_icon_hash(), format, Some(size))
+    }
+
+    /// Returns the number of emoji slots left in the guild, for either static or animated emojis.
+    ///
+    /// The number of slots depends on the guild's [`PremiumTier`], ranging from 50 at
+    /// [`PremiumTier::Tier0`] to 250 at [`PremiumTier::Tier3`], for static and animated emojis
+    /// each.
+    #[must_use]
+    pub fn emoji_slots(&self, animated: bool) -> usize {
+        let mut limit: usize = match self.premium_tier {
+            PremiumTier::Tier1 => 100,
+            PremiumTier::Tier2 => 150,
+            PremiumTier::Tier3 => 250,
+            _ => 50,
+        };
+        if self.has_feature("MORE_EMOJI") {
+            limit = limit.max(200);
+        }
+
+        let used = self.emojis.values().filter(|emoji| emoji.animated == animated).count();
+        limit.saturating_sub(used)
+    }
+
+    /// Returns the MIME type of the emoji image, or [`ModelError::EmojiSlotsFull`] if there is no
+    /// slot left for it.
+    fn check_emoji_slots(&self, data: &[u8]) -> StdResult<&'static str, ModelError> {
+        let (mime, animated) = emoji_image_format(data);
+        if self.emoji_slots(animated) == 0 {
+            return Err(ModelError::EmojiSlotsFull);
+        }
+
+        Ok(mime)
+    }
+
+    /// Returns an iterator over the guild's emojis that are restricted to the given role.
+    ///
+    /// Emojis usable by everyone are not included, see [`Self::unrestricted_emojis`] for those.
+    pub fn emojis_for_role(&self, role_id: RoleId) -> impl Iterator<Item = &Emoji> {
+        Self::emojis_for_role_(&self.emojis, role_id)
+    }
+
+    pub(crate) fn emojis_for_role_(
+        emojis: &HashMap<EmojiId, Emoji>,
+        role_id: RoleId,
+    ) -> impl Iterator<Item = &Emoji> {
+        emojis.values().filter(move |emoji| emoji.roles.contains(&role_id))
+    }
+
+    /// Returns an iterator over the guild's emojis that are not restricted to any role, and can
+    /// thus be used by every member.
+    pub fn unrestricted_emojis(&self) -> impl Iterator<Item = &Emoji> {
+        self.emojis.values().filter(|emoji| emoji.roles.is_empty())
     }
 
     /// Gets all [`Emoji`]s of this guild via HTTP.
@@ -1522,6 +2075,123 @@ impl Guild {
         self.id.emoji(http, emoji_id).await
     }
 
+    /// Gets all [`Sticker`]s of this guild via HTTP.
+    ///
+    /// Unlike the `stickers` field, which is a snapshot taken when the guild was received, this
+    /// always reflects the current state of the guild.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the guild is unavailable.
+    #[inline]
+    pub async fn stickers(&self, http: impl AsRef<Http>) -> Result<Vec<Sticker>> {
+        self.id.stickers(http).await
+    }
+
+    /// Gets a [`Sticker`] of this guild by its Id via HTTP.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if a sticker with that Id does not exist in the guild.
+    #[inline]
+    pub async fn sticker(&self, http: impl AsRef<Http>, sticker_id: StickerId) -> Result<Sticker> {
+        self.id.sticker(http, sticker_id).await
+    }
+
+    /// Gets all [`GuildTemplate`]s of this guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn templates(&self, http: impl AsRef<Http>) -> Result<Vec<GuildTemplate>> {
+        self.id.templates(http).await
+    }
+
+    /// Creates a [`GuildTemplate`] from the guild's current state.
+    ///
+    /// Refer to [`GuildId::create_template`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user lacks permission, or if invalid data is
+    /// given.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn create_template(
+        &self,
+        http: impl AsRef<Http>,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<GuildTemplate> {
+        self.id.create_template(http, name, description).await
+    }
+
+    /// Syncs a [`GuildTemplate`] to the guild's current state.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user lacks permission, or if no template with
+    /// the given code exists for the guild.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn sync_template(&self, http: impl AsRef<Http>, code: &str) -> Result<GuildTemplate> {
+        self.id.sync_template(http, code).await
+    }
+
+    /// Deletes a [`GuildTemplate`], returning the deleted template.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user lacks permission, or if no template with
+    /// the given code exists for the guild.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn delete_template(
+        &self,
+        http: impl AsRef<Http>,
+        code: &str,
+    ) -> Result<GuildTemplate> {
+        self.id.delete_template(http, code).await
+    }
+
+    /// Gets all [`SoundboardSound`]s of this guild via HTTP.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the guild is unavailable.
+    #[inline]
+    pub async fn soundboard_sounds(&self, http: impl AsRef<Http>) -> Result<Vec<SoundboardSound>> {
+        self.id.soundboard_sounds(http).await
+    }
+
+    /// Gets a [`SoundboardSound`] of this guild by its ID via HTTP.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if a sound with the given Id does not exist for the guild.
+    #[inline]
+    pub async fn soundboard_sound(
+        &self,
+        http: impl AsRef<Http>,
+        sound_id: SoundId,
+    ) -> Result<SoundboardSound> {
+        self.id.soundboard_sound(http, sound_id).await
+    }
+
     /// Gets all integration of the guild.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
@@ -1540,6 +2210,9 @@ impl Guild {
 
     /// Retrieves the active invites for the guild.
     ///
+    /// The returned invites include their uses, inviter, and expiry, which can be compared with
+    /// [`InviteCreateEvent`]s and [`InviteDeleteEvent`]s to track which invite a member used.
+    ///
     /// **Note**: Requires the [Manage Guild] permission.
     ///
     /// # Errors
@@ -1612,6 +2285,38 @@ impl Guild {
         self.id.current_user_member(http).await
     }
 
+    /// Returns the current user's [`Member`] object from [`Self::members`], using the cache to
+    /// know the current user's Id.
+    ///
+    /// Returns [`None`] if the current user is not among the guild's cached members. To fetch it
+    /// over the REST API instead, use [`Self::current_user_member`].
+    #[cfg(feature = "cache")]
+    #[must_use]
+    pub fn current_member(&self, cache: impl AsRef<Cache>) -> Option<&Member> {
+        let user_id = cache.as_ref().current_user().id;
+        self.members.get(&user_id)
+    }
+
+    /// Returns the current user's nickname in the guild, if they have one.
+    ///
+    /// Returns [`None`] if the current user has no nickname, or is not among the guild's cached
+    /// members. See [`Self::current_member`].
+    #[cfg(feature = "cache")]
+    #[must_use]
+    pub fn current_user_nick(&self, cache: impl AsRef<Cache>) -> Option<&str> {
+        self.current_member(cache)?.nick.as_deref()
+    }
+
+    /// Whether the current user is the owner of the guild.
+    ///
+    /// Some actions, such as [`Self::delete`] and [`Self::edit_mfa_level`], can only be performed
+    /// by the guild owner.
+    #[cfg(feature = "cache")]
+    #[must_use]
+    pub fn is_owned_by_current_user(&self, cache: impl AsRef<Cache>) -> bool {
+        self.owner_id == cache.as_ref().current_user().id
+    }
+
     /// Leaves the guild.
     ///
     /// # Errors
@@ -1623,6 +2328,46 @@ impl Guild {
         self.id.leave(http).await
     }
 
+    /// Returns the maximum bitrate, in bits per second, that voice channels in the guild can be
+    /// set to.
+    ///
+    /// This depends on the guild's [`PremiumTier`], ranging from 96kbps at [`PremiumTier::Tier0`]
+    /// to 384kbps at [`PremiumTier::Tier3`]. Guilds with the `VIP_REGIONS` feature always have the
+    /// 384kbps limit.
+    ///
+    /// **Note**: For an unknown premium tier, the [`PremiumTier::Tier0`] limit is returned.
+    #[must_use]
+    pub fn max_bitrate(&self) -> u32 {
+        if self.has_feature("VIP_REGIONS") {
+            return 384_000;
+        }
+
+        match self.premium_tier {
+            PremiumTier::Tier1 => 128_000,
+            PremiumTier::Tier2 => 256_000,
+            PremiumTier::Tier3 => 384_000,
+            _ => 96_000,
+        }
+    }
+
+    /// Returns the maximum size, in bytes, of the attachments that can be uploaded in a single
+    /// message in the guild.
+    ///
+    /// This depends on the guild's [`PremiumTier`], ranging from 10MiB at [`PremiumTier::Tier0`]
+    /// to 100MiB at [`PremiumTier::Tier3`].
+    ///
+    /// **Note**: For an unknown premium tier, the [`PremiumTier::Tier0`] limit is returned.
+    #[must_use]
+    pub fn upload_limit_bytes(&self) -> u64 {
+        const MIB: u64 = 1024 * 1024;
+
+        match self.premium_tier {
+            PremiumTier::Tier2 => 50 * MIB,
+            PremiumTier::Tier3 => 100 * MIB,
+            _ => 10 * MIB,
+        }
+    }
+
     /// Gets a user's [`Member`] for the guild by Id.
     ///
     /// If the cache feature is enabled [`Self::members`] will be checked first, if so, a reference
@@ -1670,6 +2415,110 @@ impl Guild {
         self.id.members(http, limit, after).await
     }
 
+    /// Gets all members in this guild that are currently boosting it, i.e. that have
+    /// [`Member::premium_since`] set.
+    ///
+    /// **Note**: This will only include members that are cached. Use
+    /// [`Self::premium_subscription_count`] for the number of boosts of the whole guild.
+    pub fn boosters(&self) -> impl Iterator<Item = &Member> {
+        self.members.values().filter(|member| member.premium_since.is_some())
+    }
+
+    /// Returns the number of members that are cached, i.e. the length of [`Self::members`].
+    ///
+    /// This can be lower than [`Self::member_count`], which is the total number of members in the
+    /// guild as reported by the gateway, if not all members have been received yet.
+    #[must_use]
+    pub fn cached_member_count(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Whether every member of the guild is cached, i.e. whether [`Self::cached_member_count`] has
+    /// reached [`Self::member_count`].
+    ///
+    /// If not, member chunks can be requested via [`ShardMessenger::chunk_guild`] before running
+    /// an operation over all members of the guild.
+    ///
+    /// [`ShardMessenger::chunk_guild`]: crate::gateway::ShardMessenger::chunk_guild
+    #[must_use]
+    pub fn is_fully_cached(&self) -> bool {
+        self.members.len() as u64 >= self.member_count
+    }
+
+    /// Gets all members in this guild that haven't passed membership screening yet, i.e. whose
+    /// [`Member::pending`] is `true`.
+    ///
+    /// **Note**: This will only include members that are cached.
+    pub fn pending_members(&self) -> impl Iterator<Item = &Member> {
+        self.members.values().filter(|member| member.is_pending())
+    }
+
+    /// Gets the number of members in this guild that are currently boosting it.
+    ///
+    /// **Note**: This will only count members that are cached, see [`Self::boosters`].
+    #[must_use]
+    pub fn booster_count(&self) -> usize {
+        self.boosters().count()
+    }
+
+    /// Gets a list of all the members in this guild, sorted by the date they joined, oldest first.
+    ///
+    /// Members without a known join date are sorted last. Members that joined at the same time are
+    /// sorted by their user Id.
+    ///
+    /// **Note**: This will only include members that are cached.
+    #[must_use]
+    pub fn members_by_join_date(&self) -> Vec<&Member> {
+        let mut members: Vec<_> = self.members.values().collect();
+        members.sort_unstable_by_key(|member| {
+            (member.joined_at.is_none(), member.joined_at, member.user.id)
+        });
+
+        members
+    }
+
+    /// Gets a list of all the channels in this guild, in the order the Discord client displays
+    /// them.
+    ///
+    /// Channels without a category come first, followed by each category ordered by position.
+    /// Every category is directly followed by its children, with voice and stage channels placed
+    /// below all other channels. Channels with the same position are ordered by their Id.
+    ///
+    /// **Note**: Threads are not included, as they are stored in [`Self::threads`].
+    #[must_use]
+    pub fn channels_in_client_order(&self) -> Vec<&GuildChannel> {
+        let is_category = |channel: &GuildChannel| channel.kind == ChannelType::Category;
+
+        let mut categories: Vec<_> = self.channels.values().filter(|c| is_category(c)).collect();
+        categories.sort_unstable_by_key(|category| (category.position, category.id));
+
+        let mut children: HashMap<Option<ChannelId>, Vec<&GuildChannel>> = HashMap::new();
+        for channel in self.channels.values().filter(|c| !is_category(c)) {
+            // Channels whose category is unknown are shown as if they had no category.
+            let parent_id = channel
+                .parent_id
+                .filter(|id| self.channels.get(id).is_some_and(is_category));
+            children.entry(parent_id).or_default().push(channel);
+        }
+
+        let mut take_children = |parent_id| {
+            let mut channels = children.remove(&parent_id).unwrap_or_default();
+            channels.sort_unstable_by_key(|channel| {
+                let is_voice = matches!(channel.kind, ChannelType::Voice | ChannelType::Stage);
+                (is_voice, channel.position, channel.id)
+            });
+            channels
+        };
+
+        let mut ordered = take_children(None);
+        for category in categories {
+            ordered.push(category);
+            ordered.extend(take_children(Some(category.id)));
+        }
+
+        ordered
+    }
+
     /// Gets a list of all the members (satisfying the status provided to the function) in this
     /// guild.
     pub fn members_with_status(&self, status: OnlineStatus) -> impl Iterator<Item = &Member> {
@@ -1679,6 +2528,45 @@ impl Guild {
         })
     }
 
+    /// Calculates the ratio of online members to total members, using
+    /// [`Self::approximate_presence_count`] and [`Self::approximate_member_count`].
+    ///
+    /// Returns [`None`] if either count is missing, or if the member count is zero.
+    ///
+    /// **Note**: The approximate counts are only sent by Discord when explicitly requesting them,
+    /// e.g. via [`GuildId::to_partial_guild_with_counts`].
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn online_ratio(&self) -> Option<f32> {
+        let presences = self.approximate_presence_count?;
+        let members = self.approximate_member_count?;
+
+        (members != 0).then(|| presences as f32 / members as f32)
+    }
+
+    /// Gets the cached presence of the user with the given Id.
+    ///
+    /// **Note**: This will always return [`None`] unless the "guild presences" privileged intent
+    /// is enabled.
+    #[must_use]
+    pub fn presence(&self, user_id: UserId) -> Option<&Presence> {
+        self.presences.get(&user_id)
+    }
+
+    /// Gets a list of all the members in this guild that have an activity with the given name in
+    /// their presence, e.g. everyone playing a certain game.
+    ///
+    /// **Note**: This will always be empty unless the "guild presences" privileged intent is
+    /// enabled.
+    pub fn playing<'a>(&'a self, activity_name: &'a str) -> impl Iterator<Item = &'a Member> {
+        self.members.iter().filter_map(move |(id, member)| match self.presences.get(id) {
+            Some(presence) if presence.activities.iter().any(|a| a.name == activity_name) => {
+                Some(member)
+            },
+            _ => None,
+        })
+    }
+
     /// Retrieves the first [`Member`] found that matches the name - with an optional discriminator
     /// - provided.
     ///
@@ -1713,6 +2601,30 @@ impl Guild {
         self.members.values().find(|member| member.nick.as_ref().is_some_and(|nick| nick == name))
     }
 
+    /// Retrieves all [`Member`]s whose [display name] is exactly the given name.
+    ///
+    /// Unlike [`Self::member_named`], which returns the first match, this returns every matching
+    /// member, which is useful for detecting members impersonating others.
+    ///
+    /// **Note**: This will only search members that are cached. If you want to search all members
+    /// in the guild via the Http API, use [`Self::search_members`].
+    ///
+    /// [display name]: Member::display_name
+    #[must_use]
+    pub fn members_by_display_name(&self, name: &str, case_sensitive: bool) -> Vec<&Member> {
+        self.members
+            .values()
+            .filter(|member| {
+                let display_name = member.display_name();
+                if case_sensitive {
+                    display_name == name
+                } else {
+                    display_name.to_lowercase() == name.to_lowercase()
+                }
+            })
+            .collect()
+    }
+
     /// Retrieves all [`Member`] that start with a given [`String`].
     ///
     /// `sorted` decides whether the best early match of the `prefix` should be the criteria to
@@ -1973,6 +2885,85 @@ impl Guild {
         self.id.move_member(cache_http, user_id, channel_id).await
     }
 
+    /// Times out a member until the given time, preventing them from sending messages, reacting,
+    /// or joining voice channels.
+    ///
+    /// **Note**: Requires the [Moderate Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// See [`GuildId::timeout_member`].
+    ///
+    /// [Moderate Members]: Permissions::MODERATE_MEMBERS
+    #[doc(alias = "disable_communication_until")]
+    pub async fn timeout_member(
+        &self,
+        cache_http: impl CacheHttp,
+        user_id: impl Into<UserId>,
+        until: Timestamp,
+        reason: Option<&str>,
+    ) -> Result<Member> {
+        self.id.timeout_member(cache_http, user_id, until, reason).await
+    }
+
+    /// Removes the timeout of a member, if they have one.
+    ///
+    /// **Note**: Requires the [Moderate Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Moderate Members]: Permissions::MODERATE_MEMBERS
+    #[doc(alias = "enable_communication")]
+    pub async fn remove_timeout(
+        &self,
+        cache_http: impl CacheHttp,
+        user_id: impl Into<UserId>,
+        reason: Option<&str>,
+    ) -> Result<Member> {
+        self.id.remove_timeout(cache_http, user_id, reason).await
+    }
+
+    /// Moves a member to the given voice or stage channel, or disconnects them from voice if
+    /// `channel_id` is [`None`].
+    ///
+    /// **Note**: Requires the [Move Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns [`ModelError::InvalidChannelType`] if the target channel
+    /// is cached and is neither a voice nor a stage channel.
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the member is not
+    /// currently in a voice channel for this guild.
+    ///
+    /// [Move Members]: Permissions::MOVE_MEMBERS
+    pub async fn move_member_to_voice(
+        &self,
+        cache_http: impl CacheHttp,
+        user_id: impl Into<UserId>,
+        channel_id: Option<ChannelId>,
+        reason: Option<&str>,
+    ) -> Result<Member> {
+        self.id.move_member_to_voice(cache_http, user_id, channel_id, reason).await
+    }
+
+    /// Calculate the current user's permissions in a given channel in the guild.
+    ///
+    /// Returns [`None`] if the current user is not among the guild's cached members. See
+    /// [`Self::current_member`].
+    #[cfg(feature = "cache")]
+    #[must_use]
+    pub fn my_permissions_in(
+        &self,
+        cache: impl AsRef<Cache>,
+        channel: &GuildChannel,
+    ) -> Option<Permissions> {
+        let member = self.current_member(cache)?;
+        Some(self.user_permissions_in(channel, member))
+    }
+
     /// Calculate a [`Member`]'s permissions in a given channel in the guild.
     #[inline]
     #[must_use]
@@ -2227,7 +3218,8 @@ impl Guild {
     ///
     /// # Errors
     ///
-    /// Returns an [`Error::Http`] if the API returns an error.
+    /// Returns [`Error::NotInRange`] if `limit` is not between 1 and 1000. Otherwise returns an
+    /// [`Error::Http`] if the API returns an error.
     #[inline]
     pub async fn search_members(
         &self,
@@ -2362,7 +3354,37 @@ impl Guild {
     /// Returns the formatted URL of the guild's splash image, if one exists.
     #[must_use]
     pub fn splash_url(&self) -> Option<String> {
-        self.splash.as_ref().map(|splash| cdn!("/splashes/{}/{}.webp?size=4096", self.id, splash))
+        splash_url(self.id, self.splash.as_ref(), Some(ImageFormat::WebP), 4096)
+    }
+
+    /// Returns the formatted URL of the guild's splash image in the given format and size, if one
+    /// exists.
+    ///
+    /// If no format is given, this behaves like [`Self::splash_url`]. See [`ImageFormat`] for
+    /// how the size is rounded.
+    #[must_use]
+    pub fn splash_url_sized(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        splash_url(self.id, self.splash.as_ref(), format, size)
+    }
+
+    /// Returns the formatted URL of the guild's discovery splash image, if one exists.
+    #[must_use]
+    pub fn discovery_splash_url(&self) -> Option<String> {
+        discovery_splash_url(self.id, self.discovery_splash.as_ref(), Some(ImageFormat::WebP), 4096)
+    }
+
+    /// Returns the formatted URL of the guild's discovery splash image in the given format and
+    /// size, if one exists.
+    ///
+    /// If no format is given, this behaves like [`Self::discovery_splash_url`]. See
+    /// [`ImageFormat`] for how the size is rounded.
+    #[must_use]
+    pub fn discovery_splash_url_sized(
+        &self,
+        format: Option<ImageFormat>,
+        size: u16,
+    ) -> Option<String> {
+        discovery_splash_url(self.id, self.discovery_splash.as_ref(), format, size)
     }
 
     /// Starts an integration sync for the given integration Id.
@@ -2414,6 +3436,41 @@ impl Guild {
         self.id.start_prune(cache_http.http(), days).await
     }
 
+    /// Starts a prune of [`Member`]s, with additional options.
+    ///
+    /// Refer to [`GuildId::start_prune_with_options`] for more information.
+    ///
+    /// **Note**: Requires [Kick Members] and [Manage Guild] permissions.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
+    /// does not have permission to kick members.
+    ///
+    /// Otherwise, see [`GuildId::start_prune_with_options`].
+    ///
+    /// [Kick Members]: Permissions::KICK_MEMBERS
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn start_prune_with_options(
+        &self,
+        cache_http: impl CacheHttp,
+        days: u8,
+        include_roles: &[RoleId],
+        compute_count: bool,
+        reason: Option<&str>,
+    ) -> Result<GuildPrune> {
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                self.require_perms(cache, Permissions::KICK_MEMBERS | Permissions::MANAGE_GUILD)?;
+            }
+        }
+
+        self.id
+            .start_prune_with_options(cache_http.http(), days, include_roles, compute_count, reason)
+            .await
+    }
+
     /// Unbans the given [`User`] from the guild.
     ///
     /// **Note**: Requires the [Ban Members] permission.
@@ -2441,6 +3498,26 @@ impl Guild {
         self.id.unban(cache_http.http(), user_id).await
     }
 
+    /// # Errors
+    ///
+    /// In addition to the reasons [`Self::unban`] may return an error, may also return an error if
+    /// the reason is too long.
+    pub async fn unban_with_reason(
+        &self,
+        cache_http: impl CacheHttp,
+        user_id: impl Into<UserId>,
+        reason: &str,
+    ) -> Result<()> {
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                self.require_perms(cache, Permissions::BAN_MEMBERS)?;
+            }
+        }
+
+        self.id.unban_with_reason(cache_http.http(), user_id, reason).await
+    }
+
     /// Retrieve's the guild's vanity URL.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
@@ -2456,16 +3533,37 @@ impl Guild {
         self.id.vanity_url(http).await
     }
 
-    /// Retrieves the guild's webhooks.
+    /// Retrieves the guild's vanity invite code along with its number of uses.
     ///
-    /// **Note**: Requires the [Manage Webhooks] permission.
+    /// **Note**: Requires the [Manage Guild] permission.
     ///
-    /// [Manage Webhooks]: Permissions::MANAGE_WEBHOOKS
+    /// # Errors
+    ///
+    /// Returns [`ModelError::MissingGuildFeature`] if the guild does not have the `VANITY_URL`
+    /// feature.
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn vanity_invite(&self, http: impl AsRef<Http>) -> Result<VanityInviteInfo> {
+        if !self.has_feature("VANITY_URL") {
+            return Err(Error::Model(ModelError::MissingGuildFeature("VANITY_URL")));
+        }
+
+        self.id.vanity_invite(http).await
+    }
+
+    /// Retrieves the webhooks of every channel in the guild. To only list the webhooks of a single
+    /// channel, use [`GuildChannel::webhooks`].
+    ///
+    /// **Note**: Requires the [Manage Webhooks] permission.
     ///
     /// # Errors
     ///
     /// Will return an [`Error::Http`] if the current user is lacking permissions. Can also return
     /// an [`Error::Json`] if there is an error deserializing the API response.
+    ///
+    /// [Manage Webhooks]: Permissions::MANAGE_WEBHOOKS
     #[inline]
     pub async fn webhooks(&self, http: impl AsRef<Http>) -> Result<Vec<Webhook>> {
         self.id.webhooks(http).await
@@ -2503,6 +3601,98 @@ impl Guild {
         self.roles.values().find(|role| role_name == role.name)
     }
 
+    /// Obtain a reference to a channel by its name.
+    ///
+    /// **Note**: Channel names are not unique. If two or more channels have the same name,
+    /// obtained reference will be one of them. Use [`Self::channel_by_name_and_kind`] to narrow
+    /// the search down to a single kind of channel.
+    ///
+    /// Threads are not searched, as they are stored in [`Self::threads`].
+    #[must_use]
+    pub fn channel_by_name(&self, name: &str) -> Option<&GuildChannel> {
+        self.channels.values().find(|channel| name == channel.name)
+    }
+
+    /// Obtain a reference to a channel of the given [`ChannelType`] by its name.
+    ///
+    /// **Note**: If two or more channels of the kind have the same name, obtained reference will
+    /// be one of them.
+    #[must_use]
+    pub fn channel_by_name_and_kind(&self, name: &str, kind: ChannelType) -> Option<&GuildChannel> {
+        self.channels.values().find(|channel| channel.kind == kind && name == channel.name)
+    }
+
+    /// Resolves a channel mention, such as `<#81384788765712384>`, to a channel or thread of this
+    /// guild.
+    ///
+    /// Returns [`None`] if the mention is invalid or the channel is not in this guild. See
+    /// [`utils::parse_channel_mention`] for the accepted syntax.
+    ///
+    /// [`utils::parse_channel_mention`]: crate::utils::parse_channel_mention
+    #[must_use]
+    pub fn resolve_channel_mention(&self, mention: &str) -> Option<&GuildChannel> {
+        let channel_id = crate::utils::parse_channel_mention(mention.trim())?;
+        self.channels
+            .get(&channel_id)
+            .or_else(|| self.threads.iter().find(|thread| thread.id == channel_id))
+    }
+
+    /// Resolves a user mention, such as `<@114941315417899012>` or the nickname variant
+    /// `<@!114941315417899012>`, to a cached member of this guild.
+    ///
+    /// Returns [`None`] if the mention is invalid or the member is not cached. See
+    /// [`utils::parse_user_mention`] for the accepted syntax.
+    ///
+    /// [`utils::parse_user_mention`]: crate::utils::parse_user_mention
+    #[must_use]
+    pub fn resolve_member_mention(&self, mention: &str) -> Option<&Member> {
+        let mention = mention.trim();
+        if !mention.ends_with('>') || mention.starts_with("<@&") {
+            return None;
+        }
+
+        self.members.get(&crate::utils::parse_user_mention(mention)?)
+    }
+
+    /// Resolves a role mention, such as `<@&136107769680887808>`, to a role of this guild.
+    ///
+    /// Returns [`None`] if the mention is invalid or the role is not in this guild. See
+    /// [`utils::parse_role_mention`] for the accepted syntax.
+    ///
+    /// [`utils::parse_role_mention`]: crate::utils::parse_role_mention
+    #[must_use]
+    pub fn resolve_role_mention(&self, mention: &str) -> Option<&Role> {
+        Self::resolve_role_mention_(&self.roles, mention)
+    }
+
+    pub(crate) fn resolve_role_mention_<'a>(
+        roles: &'a HashMap<RoleId, Role>,
+        mention: &str,
+    ) -> Option<&'a Role> {
+        roles.get(&crate::utils::parse_role_mention(mention.trim())?)
+    }
+
+    /// Returns the active threads in this guild that were created by the given user, e.g. to clean
+    /// up the forum posts of a member leaving the guild.
+    ///
+    /// **Note**: This only searches [`Self::threads`], which contains the active threads the
+    /// current user can view. Archived threads are not included.
+    pub fn threads_owned_by(&self, user_id: UserId) -> impl Iterator<Item = &GuildChannel> {
+        self.threads.iter().filter(move |thread| thread.owner_id == Some(user_id))
+    }
+
+    /// Gets all roles in the guild that are managed by a bot or an integration, i.e. roles that
+    /// have a [`RoleTags::bot_id`] or [`RoleTags::integration_id`].
+    ///
+    /// These roles are assigned automatically and can not be given to members manually.
+    pub fn integration_roles(&self) -> impl Iterator<Item = &Role> {
+        Self::integration_roles_(&self.roles)
+    }
+
+    pub(crate) fn integration_roles_(roles: &HashMap<RoleId, Role>) -> impl Iterator<Item = &Role> {
+        roles.values().filter(|role| role.is_bot_managed() || role.integration_id().is_some())
+    }
+
     /// Returns a builder which can be awaited to obtain a message or stream of messages in this
     /// guild.
     #[cfg(feature = "collector")]
@@ -2543,6 +3733,100 @@ impl Guild {
     }
 }
 
+impl Guild {
+    /// Returns the roles of the guild sorted by their rank in the role hierarchy, from lowest to
+    /// highest.
+    ///
+    /// Roles are ordered by position. Of roles sharing a position, the one with the lower Id ranks
+    /// higher, as in [`Self::member_highest_role`], and thus comes later.
+    ///
+    /// This sorts the roles on every call. See [`Self::roles_sorted_cached`] for the order kept up
+    /// to date alongside the roles.
+    #[must_use]
+    pub fn roles_sorted(&self) -> Vec<&Role> {
+        Self::roles_sorted_(&self.roles)
+    }
+
+    pub(crate) fn roles_sorted_(roles: &HashMap<RoleId, Role>) -> Vec<&Role> {
+        let mut roles: Vec<_> = roles.values().collect();
+        roles.sort_unstable_by_key(|role| (role.position, Reverse(role.id)));
+        roles
+    }
+
+    /// Returns the `@everyone` role of the guild, which shares its Id with the guild.
+    ///
+    /// Returns [`None`] if the role is not present in [`Self::roles`].
+    #[must_use]
+    pub fn everyone_role(&self) -> Option<&Role> {
+        Self::everyone_role_(&self.roles, self.id)
+    }
+
+    pub(crate) fn everyone_role_(
+        roles: &HashMap<RoleId, Role>,
+        guild_id: GuildId,
+    ) -> Option<&Role> {
+        roles.get(&RoleId::new(guild_id.get()))
+    }
+
+    /// Returns whether the guild has the given feature enabled, e.g. `"COMMUNITY"`.
+    ///
+    /// See [`Self::features`] for the list of features.
+    #[must_use]
+    pub fn has_feature(&self, feature: &str) -> bool {
+        Self::has_feature_(&self.features, feature)
+    }
+
+    pub(crate) fn has_feature_(features: &[String], feature: &str) -> bool {
+        features.iter().any(|f| f == feature)
+    }
+
+    /// Returns the Ids of the guild's roles in the order of [`Self::roles_sorted`], without
+    /// sorting them again.
+    ///
+    /// The order is computed when the guild is deserialized and recomputed whenever the cache or
+    /// one of the guild's methods adds, removes or repositions a role. This is worthwhile for
+    /// guilds with many roles whose order is needed repeatedly, e.g. when rendering role
+    /// hierarchies. Otherwise, prefer [`Self::roles_sorted`], which is always correct.
+    #[must_use]
+    pub fn roles_sorted_cached(&self) -> &[RoleId] {
+        &self.sorted_role_ids
+    }
+
+    /// Recomputes the order returned by [`Self::roles_sorted_cached`] from [`Self::roles`].
+    ///
+    /// This must be called after modifying [`Self::roles`] directly.
+    pub fn refresh_sorted_role_ids(&mut self) {
+        self.sorted_role_ids = Self::roles_sorted_(&self.roles).into_iter().map(|r| r.id).collect();
+    }
+
+    /// Applies a voice state update to [`Self::voice_states`], returning the user's previous
+    /// voice state, if any.
+    ///
+    /// The state is inserted or updated by its [`VoiceState::user_id`], or removed if its
+    /// [`VoiceState::channel_id`] is [`None`], i.e. the user disconnected.
+    pub fn apply_voice_state(&mut self, state: VoiceState) -> Option<VoiceState> {
+        if state.channel_id.is_some() {
+            self.voice_states.insert(state.user_id, state)
+        } else {
+            self.voice_states.remove(&state.user_id)
+        }
+    }
+
+    /// Applies a presence update to [`Self::presences`].
+    ///
+    /// The presence is inserted or updated by the user's Id, or removed if the user went
+    /// [offline], so that the presence map only ever holds online members.
+    ///
+    /// [offline]: OnlineStatus::Offline
+    pub fn apply_presence(&mut self, presence: Presence) {
+        if presence.status == OnlineStatus::Offline {
+            self.presences.remove(&presence.user.id);
+        } else {
+            self.presences.insert(presence.user.id, presence);
+        }
+    }
+}
+
 #[cfg(feature = "model")]
 struct CalculatePermissions {
     /// Whether the guild member is the guild owner
@@ -2629,6 +3913,36 @@ fn calculate_permissions(data: CalculatePermissions) -> Permissions {
     permissions
 }
 
+/// Detects the MIME type of an emoji image from its magic bytes, and whether it is animated.
+///
+/// Unknown formats are sent as PNG, leaving it to Discord to reject them.
+#[cfg(feature = "model")]
+fn emoji_image_format(data: &[u8]) -> (&'static str, bool) {
+    if let Some(mut chunks) = data.strip_prefix(b"\x89PNG\r\n\x1a\n") {
+        // An APNG has an acTL chunk before the first IDAT chunk.
+        while let [l0, l1, l2, l3, kind @ ..] = chunks {
+            let len = u32::from_be_bytes([*l0, *l1, *l2, *l3]) as usize;
+            match kind.get(..4) {
+                Some(b"acTL") => return ("image/png", true),
+                Some(b"IDAT") | None => break,
+                Some(_) => chunks = kind.get(len + 8..).unwrap_or_default(),
+            }
+        }
+        ("image/png", false)
+    } else if data.starts_with(b"\xFF\xD8\xFF") {
+        ("image/jpeg", false)
+    } else if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+        ("image/gif", true)
+    } else if data.starts_with(b"RIFF") && data.get(8..12) == Some(b"WEBP") {
+        // Animated WEBPs use the extended VP8X format with the animation flag set.
+        let animated =
+            data.get(12..16) == Some(b"VP8X") && data.get(20).is_some_and(|flags| flags & 0x02 != 0);
+        ("image/webp", animated)
+    } else {
+        ("image/png", false)
+    }
+}
+
 /// Checks if a `&str` contains another `&str`.
 #[cfg(feature = "model")]
 fn contains(haystack: &str, needle: &str, case_sensitive: bool) -> bool {
@@ -2678,7 +3992,22 @@ pub struct GuildWidget {
 #[non_exhaustive]
 pub struct GuildPrune {
     /// The number of members that would be pruned by the operation.
-    pub pruned: u64,
+    ///
+    /// This is [`None`] when starting a prune with `compute_prune_count` set to `false`.
+    pub pruned: Option<u64>,
+}
+
+/// Information about a guild's vanity invite.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#get-guild-vanity-url).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct VanityInviteInfo {
+    /// The vanity invite code, if one is set.
+    pub code: Option<String>,
+    /// The number of times the vanity invite has been used.
+    pub uses: u64,
 }
 
 /// Variant of [`Guild`] returned from [`Http::get_guilds`].
@@ -2714,7 +4043,16 @@ impl GuildInfo {
     /// This will produce a WEBP image URL, or GIF if the guild has a GIF icon.
     #[must_use]
     pub fn icon_url(&self) -> Option<String> {
-        icon_url(self.id, self.icon.as_ref())
+        icon_url(self.id, self.icon.as_ref(), None, None)
+    }
+
+    /// Returns the formatted URL of the guild's icon in the given format and size, if one exists.
+    ///
+    /// If no format is given, this behaves like [`Self::icon_url`]. See [`ImageFormat`] for
+    /// how the size is rounded.
+    #[must_use]
+    pub fn icon_url_sized(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        icon_url(self.id, self.icon.as_ref(), format, Some(size))
     }
 }
 
@@ -2723,7 +4061,35 @@ impl InviteGuild {
     /// Returns the formatted URL of the guild's splash image, if one exists.
     #[must_use]
     pub fn splash_url(&self) -> Option<String> {
-        self.splash.as_ref().map(|splash| cdn!("/splashes/{}/{}.webp?size=4096", self.id, splash))
+        splash_url(self.id, self.splash.as_ref(), Some(ImageFormat::WebP), 4096)
+    }
+
+    /// Returns the formatted URL of the guild's splash image in the given format and size, if one
+    /// exists.
+    ///
+    /// If no format is given, this behaves like [`Self::splash_url`]. See [`ImageFormat`] for
+    /// how the size is rounded.
+    #[must_use]
+    pub fn splash_url_sized(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        splash_url(self.id, self.splash.as_ref(), format, size)
+    }
+
+    /// Returns the formatted URL of the guild's banner image, if one exists.
+    ///
+    /// This will produce a WEBP image URL, or GIF if the guild has an animated banner.
+    #[must_use]
+    pub fn banner_url(&self) -> Option<String> {
+        self.banner_url_sized(None, 1024)
+    }
+
+    /// Returns the formatted URL of the guild's banner in the given format and size, if one exists.
+    ///
+    /// If no format is given, this behaves like [`Self::banner_url`]. See [`ImageFormat`] for
+    /// how the size is rounded.
+    #[must_use]
+    pub fn banner_url_sized(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        let path = format_args!("banners/{}", self.id);
+        self.banner.as_ref().map(|banner| cdn_asset_url(path, banner, format, Some(size)))
     }
 }
 
@@ -2844,6 +4210,74 @@ enum_number! {
     }
 }
 
+/// An error that can occur when parsing a guild setting, such as a [`VerificationLevel`], from a
+/// string.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct GuildSettingParseError {
+    kind: &'static str,
+    string: String,
+}
+
+impl std::error::Error for GuildSettingParseError {}
+
+impl fmt::Display for GuildSettingParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {} {:?}", self.kind, self.string)
+    }
+}
+
+/// Lowercases the string and strips separators, so that e.g. `"Without Role"` and
+/// `"without_role"` are parsed the same.
+fn normalize_setting(s: &str) -> String {
+    s.chars().filter(|c| !matches!(c, '_' | '-' | ' ')).map(|c| c.to_ascii_lowercase()).collect()
+}
+
+macro_rules! impl_setting_from_str {
+    ($ty:ident, $kind:literal, { $($name:literal => $variant:ident,)* }) => {
+        impl FromStr for $ty {
+            type Err = GuildSettingParseError;
+
+            /// Parses the setting from its name, ignoring case and separators.
+            fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                match normalize_setting(s).as_str() {
+                    $($name => Ok(Self::$variant),)*
+                    _ => Err(GuildSettingParseError {
+                        kind: $kind,
+                        string: s.into(),
+                    }),
+                }
+            }
+        }
+    };
+}
+
+impl_setting_from_str!(VerificationLevel, "verification level", {
+    "none" => None,
+    "low" => Low,
+    "medium" => Medium,
+    "high" => High,
+    "higher" => Higher,
+});
+
+impl_setting_from_str!(ExplicitContentFilter, "explicit content filter", {
+    "none" => None,
+    "withoutrole" => WithoutRole,
+    "all" => All,
+});
+
+impl_setting_from_str!(DefaultMessageNotificationLevel, "default message notification level", {
+    "all" => All,
+    "mentions" => Mentions,
+});
+
+impl_setting_from_str!(NsfwLevel, "nsfw level", {
+    "default" => Default,
+    "explicit" => Explicit,
+    "safe" => Safe,
+    "agerestricted" => AgeRestricted,
+});
+
 enum_number! {
     /// The [`Guild`] AFK timeout length.
     ///
@@ -2907,5 +4341,664 @@ mod test {
 
             assert_eq!(lhs, gen_member().display_name());
         }
+
+        #[test]
+        fn online_ratio() {
+            let mut guild = Guild {
+                approximate_member_count: Some(4),
+                approximate_presence_count: Some(1),
+                ..Default::default()
+            };
+            assert_eq!(guild.online_ratio(), Some(0.25));
+
+            guild.approximate_member_count = Some(0);
+            assert_eq!(guild.online_ratio(), None);
+
+            guild.approximate_member_count = None;
+            assert_eq!(guild.online_ratio(), None);
+        }
+
+        #[test]
+        fn max_bitrate() {
+            let mut guild = Guild::default();
+            assert_eq!(guild.max_bitrate(), 96_000);
+
+            guild.premium_tier = PremiumTier::Tier2;
+            assert_eq!(guild.max_bitrate(), 256_000);
+
+            guild.premium_tier = PremiumTier::Tier0;
+            guild.features.push("VIP_REGIONS".into());
+            assert_eq!(guild.max_bitrate(), 384_000);
+        }
+
+        #[test]
+        fn upload_limit_bytes() {
+            let mut guild = Guild::default();
+
+            for (tier, limit) in [
+                (PremiumTier::Tier0, 10),
+                (PremiumTier::Tier1, 10),
+                (PremiumTier::Tier2, 50),
+                (PremiumTier::Tier3, 100),
+                (PremiumTier::Unknown(4), 10),
+            ] {
+                guild.premium_tier = tier;
+                assert_eq!(guild.upload_limit_bytes(), limit * 1024 * 1024);
+            }
+        }
+
+        fn gen_role(id: u64, position: u16) -> Role {
+            Role {
+                id: RoleId::new(id),
+                position,
+                ..Default::default()
+            }
+        }
+
+        fn gen_channel(id: u64, position: u16) -> GuildChannel {
+            GuildChannel {
+                id: ChannelId::new(id),
+                position,
+                ..Default::default()
+            }
+        }
+
+        fn gen_emoji(id: u64, animated: bool, roles: &[u64]) -> Emoji {
+            let roles: Vec<_> = roles.iter().map(u64::to_string).collect();
+            crate::json::from_value(crate::json::json!({
+                "id": id.to_string(),
+                "name": "blobwave",
+                "animated": animated,
+                "roles": roles,
+            }))
+            .unwrap()
+        }
+
+        #[test]
+        fn default_channel_is_ordered_by_position() {
+            let member = gen_member();
+            let mut guild = Guild {
+                owner_id: member.user.id,
+                members: HashMap::from([(member.user.id, member.clone())]),
+                ..Default::default()
+            };
+
+            for channel in [gen_channel(4, 2), gen_channel(3, 1), gen_channel(2, 1)] {
+                guild.channels.insert(channel.id, channel);
+            }
+            let mut category = gen_channel(1, 0);
+            category.kind = ChannelType::Category;
+            guild.channels.insert(category.id, category);
+
+            let expected = Some(ChannelId::new(2));
+            assert_eq!(guild.default_channel(member.user.id).map(|c| c.id), expected);
+            assert_eq!(guild.default_channel_guaranteed().map(|c| c.id), expected);
+        }
+
+        #[test]
+        fn channels_in_client_order() {
+            let gen = |id, kind, position, parent_id: Option<u64>| GuildChannel {
+                id: ChannelId::new(id),
+                kind,
+                position,
+                parent_id: parent_id.map(ChannelId::new),
+                ..Default::default()
+            };
+
+            let mut guild = Guild::default();
+            for channel in [
+                gen(1, ChannelType::Category, 1, None),
+                gen(2, ChannelType::Category, 0, None),
+                gen(3, ChannelType::Voice, 0, Some(1)),
+                gen(4, ChannelType::Text, 5, Some(1)),
+                gen(5, ChannelType::Text, 2, Some(1)),
+                gen(6, ChannelType::Stage, 0, Some(2)),
+                gen(7, ChannelType::Text, 0, Some(2)),
+                gen(8, ChannelType::Voice, 0, None),
+                gen(9, ChannelType::Text, 3, None),
+                gen(10, ChannelType::Text, 1, Some(99)),
+            ] {
+                guild.channels.insert(channel.id, channel);
+            }
+
+            let order: Vec<_> =
+                guild.channels_in_client_order().into_iter().map(|c| c.id.get()).collect();
+            assert_eq!(order, [10, 9, 8, 2, 7, 6, 1, 5, 4, 3]);
+        }
+
+        #[test]
+        fn is_voice_channel() {
+            let mut guild = Guild::default();
+            let kinds = [(1, ChannelType::Voice), (2, ChannelType::Stage), (3, ChannelType::Text)];
+            for (id, kind) in kinds {
+                let channel = GuildChannel {
+                    kind,
+                    ..gen_channel(id, 0)
+                };
+                guild.channels.insert(channel.id, channel);
+            }
+
+            assert!(guild.is_voice_channel(ChannelId::new(1)));
+            assert!(guild.is_voice_channel(ChannelId::new(2)));
+            assert!(!guild.is_voice_channel(ChannelId::new(3)));
+            assert!(!guild.is_voice_channel(ChannelId::new(4)));
+        }
+
+        #[test]
+        fn member_guild_permissions() {
+            let guild_id = GuildId::new(1);
+            let role = |id, permissions| Role {
+                guild_id,
+                permissions,
+                ..gen_role(id, 0)
+            };
+
+            let mut guild = Guild {
+                id: guild_id,
+                owner_id: UserId::new(99),
+                ..Default::default()
+            };
+            for role in [
+                role(1, Permissions::SEND_MESSAGES),
+                role(2, Permissions::BAN_MEMBERS),
+                role(3, Permissions::KICK_MEMBERS),
+            ] {
+                guild.roles.insert(role.id, role);
+            }
+
+            let mut member = Member {
+                roles: vec![RoleId::new(2)],
+                ..gen_member()
+            };
+            assert_eq!(
+                member.guild_permissions(&guild),
+                Permissions::SEND_MESSAGES | Permissions::BAN_MEMBERS
+            );
+
+            member.user.id = guild.owner_id;
+            assert_eq!(member.guild_permissions(&guild), Permissions::all());
+        }
+
+        #[test]
+        fn roles_assignable_by() {
+            let guild_id = GuildId::new(1);
+            let role = |id, position, managed| Role {
+                guild_id,
+                managed,
+                ..gen_role(id, position)
+            };
+
+            let mut guild = Guild {
+                id: guild_id,
+                owner_id: UserId::new(99),
+                ..Default::default()
+            };
+            for role in [
+                role(1, 0, false),
+                role(2, 1, false),
+                role(3, 2, true),
+                role(4, 3, false),
+                role(5, 4, false),
+            ] {
+                guild.roles.insert(role.id, role);
+            }
+
+            let assignable = |member: &Member| {
+                let mut ids: Vec<_> =
+                    guild.roles_assignable_by(member).map(|r| r.id.get()).collect();
+                ids.sort_unstable();
+                ids
+            };
+
+            let mut member = Member {
+                roles: vec![RoleId::new(4)],
+                ..gen_member()
+            };
+            assert_eq!(assignable(&member), [2]);
+
+            member.roles.clear();
+            assert!(assignable(&member).is_empty());
+
+            member.user.id = guild.owner_id;
+            assert_eq!(assignable(&member), [2, 4, 5]);
+        }
+
+        #[test]
+        fn members_by_display_name() {
+            let gen_member = |id, name: &str, global_name: Option<&str>, nick: Option<&str>| {
+                Member {
+                    user: User {
+                        id: UserId::new(id),
+                        name: name.to_string(),
+                        global_name: global_name.map(ToString::to_string),
+                        ..Default::default()
+                    },
+                    nick: nick.map(ToString::to_string),
+                    ..Default::default()
+                }
+            };
+
+            let members = [
+                gen_member(1, "zey", None, None),
+                gen_member(2, "impostor", Some("Zey"), None),
+                gen_member(3, "other", Some("zey"), Some("not zey")),
+                gen_member(4, "zeyla", None, None),
+            ];
+            let guild = Guild {
+                members: members.into_iter().map(|m| (m.user.id, m)).collect(),
+                ..Default::default()
+            };
+
+            let ids = |case_sensitive| {
+                let mut ids: Vec<_> = guild
+                    .members_by_display_name("zey", case_sensitive)
+                    .into_iter()
+                    .map(|m| m.user.id.get())
+                    .collect();
+                ids.sort_unstable();
+                ids
+            };
+            assert_eq!(ids(true), [1]);
+            assert_eq!(ids(false), [1, 2]);
+        }
+
+        #[test]
+        fn channel_by_name() {
+            let channel = |id, name: &str, kind| GuildChannel {
+                id: ChannelId::new(id),
+                name: name.to_string(),
+                kind,
+                ..Default::default()
+            };
+
+            let guild = Guild {
+                channels: [
+                    channel(1, "general", ChannelType::Voice),
+                    channel(2, "rules", ChannelType::Text),
+                ]
+                .into_iter()
+                .map(|c| (c.id, c))
+                .collect(),
+                ..Default::default()
+            };
+
+            assert_eq!(guild.channel_by_name("general").map(|c| c.id.get()), Some(1));
+            assert!(guild.channel_by_name("off-topic").is_none());
+            assert!(guild.channel_by_name_and_kind("general", ChannelType::Text).is_none());
+            assert_eq!(
+                guild.channel_by_name_and_kind("rules", ChannelType::Text).map(|c| c.id.get()),
+                Some(2)
+            );
+        }
+
+        #[test]
+        fn apply_voice_state() {
+            let state = |channel_id: Option<&str>| -> VoiceState {
+                crate::json::from_value(crate::json::json!({
+                    "channel_id": channel_id,
+                    "deaf": false,
+                    "mute": false,
+                    "self_deaf": false,
+                    "self_mute": false,
+                    "self_video": false,
+                    "session_id": "abc",
+                    "suppress": false,
+                    "user_id": "7",
+                    "request_to_speak_timestamp": null
+                }))
+                .unwrap()
+            };
+            let user_id = UserId::new(7);
+            let mut guild = Guild::default();
+
+            assert!(guild.apply_voice_state(state(Some("1"))).is_none());
+            let previous = guild.apply_voice_state(state(Some("2"))).unwrap();
+            assert_eq!(previous.channel_id, Some(ChannelId::new(1)));
+            assert_eq!(guild.voice_states[&user_id].channel_id, Some(ChannelId::new(2)));
+
+            let previous = guild.apply_voice_state(state(None)).unwrap();
+            assert_eq!(previous.channel_id, Some(ChannelId::new(2)));
+            assert!(guild.voice_states.is_empty());
+        }
+
+        #[test]
+        fn apply_presence() {
+            let presence = |status: &str| -> Presence {
+                crate::json::from_value(crate::json::json!({
+                    "user": {"id": "7"},
+                    "status": status,
+                    "activities": []
+                }))
+                .unwrap()
+            };
+            let user_id = UserId::new(7);
+            let mut guild = Guild::default();
+
+            guild.apply_presence(presence("online"));
+            guild.apply_presence(presence("idle"));
+            assert_eq!(guild.presences[&user_id].status, OnlineStatus::Idle);
+
+            guild.apply_presence(presence("offline"));
+            assert!(guild.presences.is_empty());
+        }
+
+        #[test]
+        fn is_fully_cached() {
+            let mut guild = gen();
+            guild.member_count = 2;
+            assert_eq!(guild.cached_member_count(), 1);
+            assert!(!guild.is_fully_cached());
+
+            guild.member_count = 1;
+            assert!(guild.is_fully_cached());
+        }
+
+        #[test]
+        fn roles_sorted_cached() {
+            let mut guild = Guild::default();
+            for role in [gen_role(1, 0), gen_role(2, 2), gen_role(3, 1), gen_role(4, 1)] {
+                guild.roles.insert(role.id, role);
+            }
+
+            let ids = [1, 4, 3, 2].map(RoleId::new);
+            assert_eq!(guild.roles_sorted().into_iter().map(|r| r.id).collect::<Vec<_>>(), ids);
+            assert!(guild.roles_sorted_cached().is_empty());
+            guild.refresh_sorted_role_ids();
+            assert_eq!(guild.roles_sorted_cached(), ids);
+
+            guild.roles.remove(&RoleId::new(3));
+            guild.refresh_sorted_role_ids();
+            assert_eq!(guild.roles_sorted_cached(), [1, 4, 2].map(RoleId::new));
+        }
+
+        #[test]
+        fn roles_sorted_cached_deserialized() {
+            let mut guild = Guild::default();
+            for role in [gen_role(1, 0), gen_role(2, 2), gen_role(3, 1)] {
+                guild.roles.insert(role.id, role);
+            }
+
+            let value = crate::json::to_value(&guild).unwrap();
+            let guild: Guild = crate::json::from_value(value).unwrap();
+            assert_eq!(guild.roles_sorted_cached(), [1, 3, 2].map(RoleId::new));
+        }
+
+        #[test]
+        fn member_highest_role_position() {
+            let mut guild = Guild::default();
+            for role in [gen_role(1, 3), gen_role(2, 5), gen_role(3, 5)] {
+                guild.roles.insert(role.id, role);
+            }
+
+            let mut member = gen_member();
+            assert_eq!(member.highest_role_position(&guild), None);
+
+            member.roles = vec![RoleId::new(1), RoleId::new(3), RoleId::new(2)];
+            assert_eq!(member.highest_role_position(&guild), Some((5, RoleId::new(2))));
+        }
+
+        #[test]
+        fn resolve_mentions() {
+            let mut guild = Guild::default();
+            let member = gen_member();
+            let user_id = member.user.id;
+            guild.members.insert(user_id, member);
+
+            let role = gen_role(2, 0);
+            guild.roles.insert(role.id, role);
+
+            let channel = GuildChannel {
+                id: ChannelId::new(3),
+                ..Default::default()
+            };
+            guild.channels.insert(channel.id, channel);
+
+            let resolve_member =
+                |mention: &str| guild.resolve_member_mention(mention).map(|m| m.user.id);
+            assert_eq!(resolve_member(&format!("<@{user_id}>")), Some(user_id));
+            assert_eq!(resolve_member(&format!("<@!{user_id}>")), Some(user_id));
+            assert_eq!(resolve_member(&format!("<@{user_id}")), None);
+            assert_eq!(resolve_member("<@&2>"), None);
+
+            assert_eq!(guild.resolve_role_mention("<@&2>").map(|r| r.id), Some(RoleId::new(2)));
+            assert!(guild.resolve_role_mention("<@&4>").is_none());
+
+            let channel = guild.resolve_channel_mention(" <#3> ");
+            assert_eq!(channel.map(|c| c.id), Some(ChannelId::new(3)));
+            assert!(guild.resolve_channel_mention("<#!3>").is_none());
+        }
+
+        #[test]
+        fn partial_guild_role_helpers() {
+            let mut guild = Guild {
+                id: GuildId::new(1),
+                owner_id: UserId::new(9),
+                ..Default::default()
+            };
+            for role in [gen_role(1, 0), gen_role(2, 4), gen_role(3, 2)] {
+                guild.roles.insert(role.id, role);
+            }
+
+            let mut member = gen_member();
+            member.roles = vec![RoleId::new(2)];
+
+            let partial = PartialGuild::from(guild.clone());
+            let ids = |roles: Vec<&Role>| roles.into_iter().map(|r| r.id).collect::<Vec<_>>();
+            assert_eq!(ids(partial.roles_sorted()), ids(guild.roles_sorted()));
+            assert_eq!(partial.everyone_role().map(|r| r.id), Some(RoleId::new(1)));
+            assert_eq!(guild.everyone_role().map(|r| r.id), Some(RoleId::new(1)));
+            assert_eq!(partial.member_highest_role(&member).map(|r| r.id), Some(RoleId::new(2)));
+            assert_eq!(
+                ids(partial.roles_assignable_by(&member).collect()),
+                vec![RoleId::new(3)]
+            );
+        }
+
+        #[test]
+        fn guild_builder() {
+            let guild_id = GuildId::new(1);
+            let member = gen_member();
+            let user_id = member.user.id;
+            let channel = GuildChannel {
+                id: ChannelId::new(2),
+                ..Default::default()
+            };
+
+            let guild = Guild::builder(guild_id, "guild")
+                .role(gen_role(1, 0))
+                .channel(channel)
+                .member(member)
+                .build();
+
+            assert_eq!(guild.name, "guild");
+            assert_eq!(guild.member_count, 1);
+            assert_eq!(guild.roles[&RoleId::new(1)].guild_id, guild_id);
+            assert_eq!(guild.channels[&ChannelId::new(2)].guild_id, guild_id);
+            assert_eq!(guild.members[&user_id].guild_id, guild_id);
+
+            let guild = Guild::builder(guild_id, "guild").member_count(100).build();
+            assert_eq!(guild.member_count, 100);
+        }
+
+        #[tokio::test]
+        async fn moderation_limits() {
+            use crate::constants::{BAN_DELETE_MESSAGE_SECONDS_MAX, TIMEOUT_MAX_SECS};
+            use crate::http::Http;
+
+            // The limits are checked before any request is made.
+            let http = Http::new("");
+            let guild_id = GuildId::new(1);
+            let user_id = UserId::new(2);
+
+            let now = Timestamp::now().unix_timestamp();
+            let max = TIMEOUT_MAX_SECS as i64;
+            for secs in [-60, 0, max + 60] {
+                let until = Timestamp::from_unix_timestamp(now + secs).unwrap();
+                let result = guild_id.timeout_member(&http, user_id, until, None).await;
+                assert!(matches!(result, Err(Error::NotInRange("until", ..))));
+            }
+
+            let secs = BAN_DELETE_MESSAGE_SECONDS_MAX + 1;
+            let result = guild_id.ban_with_message_deletion(&http, user_id, secs, None).await;
+            assert!(matches!(result, Err(Error::NotInRange("delete_message_seconds", ..))));
+        }
+
+        #[test]
+        fn members_by_join_date() {
+            let gen_member = |id, joined_at: Option<i64>| Member {
+                user: User {
+                    id: UserId::new(id),
+                    ..Default::default()
+                },
+                joined_at: joined_at.map(|secs| Timestamp::from_unix_timestamp(secs).unwrap()),
+                ..Default::default()
+            };
+
+            let members = [
+                gen_member(1, None),
+                gen_member(2, Some(20)),
+                gen_member(3, Some(10)),
+                gen_member(4, Some(20)),
+            ];
+            let guild = Guild {
+                members: members.into_iter().map(|m| (m.user.id, m)).collect(),
+                ..Default::default()
+            };
+
+            let ids: Vec<_> =
+                guild.members_by_join_date().into_iter().map(|m| m.user.id.get()).collect();
+            assert_eq!(ids, [3, 2, 4, 1]);
+        }
+
+        #[test]
+        fn emoji_slots() {
+            let mut guild = Guild {
+                premium_tier: PremiumTier::Tier1,
+                ..Default::default()
+            };
+            let emoji = gen_emoji(1, true, &[]);
+            guild.emojis.insert(emoji.id, emoji);
+
+            assert_eq!(guild.emoji_slots(false), 100);
+            assert_eq!(guild.emoji_slots(true), 99);
+
+            guild.features.push("MORE_EMOJI".into());
+            assert_eq!(guild.emoji_slots(false), 200);
+        }
+
+        #[test]
+        fn emoji_image_format() {
+            fn png(chunks: &[&[u8; 4]]) -> Vec<u8> {
+                let mut data = b"\x89PNG\r\n\x1a\n".to_vec();
+                for kind in chunks {
+                    data.extend([0, 0, 0, 1]);
+                    data.extend(*kind);
+                    data.extend([0; 5]);
+                }
+                data
+            }
+
+            let webp = |flags: u8| {
+                let mut data = b"RIFF\0\0\0\0WEBPVP8X\0\0\0\0".to_vec();
+                data.push(flags);
+                data
+            };
+
+            let cases: [(Vec<u8>, _); 7] = [
+                (png(&[b"IHDR", b"IDAT"]), ("image/png", false)),
+                (png(&[b"IHDR", b"acTL", b"IDAT"]), ("image/png", true)),
+                (b"\xFF\xD8\xFF\xE0".to_vec(), ("image/jpeg", false)),
+                (b"GIF89a".to_vec(), ("image/gif", true)),
+                (b"RIFF\0\0\0\0WEBPVP8 ".to_vec(), ("image/webp", false)),
+                (webp(0x02), ("image/webp", true)),
+                (webp(0x10), ("image/webp", false)),
+            ];
+            for (data, expected) in cases {
+                assert_eq!(super::super::emoji_image_format(&data), expected);
+            }
+        }
+
+        #[test]
+        fn check_emoji_slots() {
+            let mut guild = Guild::default();
+            for (id, animated) in (1..=50).map(|id| (id, false)).chain([(51, true)]) {
+                let emoji = gen_emoji(id, animated, &[]);
+                guild.emojis.insert(emoji.id, emoji);
+            }
+
+            assert!(matches!(
+                guild.check_emoji_slots(b"\xFF\xD8\xFF\xE0"),
+                Err(ModelError::EmojiSlotsFull)
+            ));
+            assert!(matches!(guild.check_emoji_slots(b"GIF89a"), Ok("image/gif")));
+        }
+
+        #[test]
+        fn emojis_for_role() {
+            let mut guild = Guild::default();
+            for (id, roles) in [(1, &[][..]), (2, &[10]), (3, &[10, 11])] {
+                let emoji = gen_emoji(id, false, roles);
+                guild.emojis.insert(emoji.id, emoji);
+            }
+
+            let mut ids: Vec<_> =
+                guild.emojis_for_role(RoleId::new(10)).map(|emoji| emoji.id.get()).collect();
+            ids.sort_unstable();
+            assert_eq!(ids, [2, 3]);
+            assert_eq!(guild.emojis_for_role(RoleId::new(11)).count(), 1);
+            assert_eq!(guild.emojis_for_role(RoleId::new(12)).count(), 0);
+
+            let unrestricted: Vec<_> = guild.unrestricted_emojis().map(|emoji| emoji.id).collect();
+            assert_eq!(unrestricted, [EmojiId::new(1)]);
+        }
+
+        #[test]
+        fn icon_url_falls_back_to_icon_hash() {
+            let hash: ImageHash = "f1eff024d9c85339c877985229ed8fee".parse().unwrap();
+            let guild = Guild {
+                id: GuildId::new(1),
+                icon_hash: Some(hash),
+                ..Default::default()
+            };
+
+            assert_eq!(guild.effective_icon_hash(), Some(&hash));
+            assert_eq!(
+                guild.icon_url().unwrap(),
+                "https://cdn.discordapp.com/icons/1/f1eff024d9c85339c877985229ed8fee.webp"
+            );
+        }
+
+        #[test]
+        fn banner_url_uses_gif_when_animated() {
+            let mut guild = Guild {
+                id: GuildId::new(1),
+                banner: Some("f1eff024d9c85339c877985229ed8fee".into()),
+                ..Default::default()
+            };
+
+            let expected = "/banners/1/f1eff024d9c85339c877985229ed8fee.webp?size=1024";
+            assert!(guild.banner_url().unwrap().ends_with(expected));
+
+            guild.banner = Some("a_f1eff024d9c85339c877985229ed8fee".into());
+            let expected = "/banners/1/a_f1eff024d9c85339c877985229ed8fee.gif?size=1024";
+            assert!(guild.banner_url().unwrap().ends_with(expected));
+        }
+    }
+
+    #[test]
+    fn guild_settings_from_str() {
+        use super::*;
+
+        assert_eq!("HIGH".parse::<VerificationLevel>().unwrap(), VerificationLevel::High);
+        assert_eq!(
+            "without_role".parse::<ExplicitContentFilter>().unwrap(),
+            ExplicitContentFilter::WithoutRole
+        );
+        assert_eq!(
+            "Mentions".parse::<DefaultMessageNotificationLevel>().unwrap(),
+            DefaultMessageNotificationLevel::Mentions
+        );
+        assert_eq!("age restricted".parse::<NsfwLevel>().unwrap(), NsfwLevel::AgeRestricted);
+        assert!("highest".parse::<VerificationLevel>().is_err());
     }
 }
@@ -0,0 +1,242 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::model::channel::ReactionType;
+use crate::model::id::{
+    ChannelId,
+    EmojiId,
+    GuildId,
+    OnboardingPromptId,
+    OnboardingPromptOptionId,
+    RoleId,
+};
+
+/// Information relating to a guild's onboarding flow, shown to new members when they join.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildOnboarding {
+    /// The Id of the guild this onboarding is part of.
+    pub guild_id: GuildId,
+    /// The prompts shown during onboarding and in the customize community tab.
+    pub prompts: Vec<OnboardingPrompt>,
+    /// The channels that members get opted into automatically.
+    pub default_channel_ids: Vec<ChannelId>,
+    /// Whether onboarding is enabled in the guild.
+    pub enabled: bool,
+    /// The criteria used to satisfy the onboarding constraints required for enabling it.
+    pub mode: OnboardingMode,
+}
+
+/// A prompt shown during a guild's onboarding.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object-onboarding-prompt-structure).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct OnboardingPrompt {
+    /// The Id of the prompt.
+    pub id: OnboardingPromptId,
+    /// The type of the prompt.
+    #[serde(rename = "type")]
+    pub kind: OnboardingPromptType,
+    /// The options available within the prompt.
+    pub options: Vec<OnboardingPromptOption>,
+    /// The title of the prompt.
+    pub title: String,
+    /// Whether users are limited to selecting a single option.
+    pub single_select: bool,
+    /// Whether the prompt is required before a user completes the onboarding flow.
+    pub required: bool,
+    /// Whether the prompt is present in the onboarding flow. If `false`, the prompt will only
+    /// appear in the customize community tab.
+    pub in_onboarding: bool,
+}
+
+/// An option available within an [`OnboardingPrompt`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object-prompt-option-structure).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct OnboardingPromptOption {
+    /// The Id of the option.
+    pub id: OnboardingPromptOptionId,
+    /// The channels a member is added to when the option is selected.
+    pub channel_ids: Vec<ChannelId>,
+    /// The roles assigned to a member when the option is selected.
+    pub role_ids: Vec<RoleId>,
+    /// The emoji of the option, if there is one.
+    pub emoji: Option<ReactionType>,
+    /// The title of the option.
+    pub title: String,
+    /// The description of the option.
+    pub description: Option<String>,
+}
+
+// Manual impl needed to accept the emoji both as an object, as Discord sends it, and as the flat
+// emoji_id, emoji_name and emoji_animated fields, as Discord expects to receive it
+impl<'de> Deserialize<'de> for OnboardingPromptOption {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct PartialEmoji {
+            id: Option<EmojiId>,
+            name: Option<String>,
+            #[serde(default)]
+            animated: bool,
+        }
+
+        #[derive(Deserialize)]
+        struct Helper {
+            id: OnboardingPromptOptionId,
+            #[serde(default)]
+            channel_ids: Vec<ChannelId>,
+            #[serde(default)]
+            role_ids: Vec<RoleId>,
+            emoji: Option<PartialEmoji>,
+            emoji_id: Option<EmojiId>,
+            emoji_name: Option<String>,
+            #[serde(default)]
+            emoji_animated: bool,
+            title: String,
+            description: Option<String>,
+        }
+
+        let helper = Helper::deserialize(deserializer)?;
+
+        let (emoji_id, emoji_name, animated) = match helper.emoji {
+            Some(emoji) => (emoji.id, emoji.name, emoji.animated),
+            None => (helper.emoji_id, helper.emoji_name, helper.emoji_animated),
+        };
+
+        let emoji = match (emoji_id, emoji_name) {
+            (Some(id), name) => Some(ReactionType::Custom {
+                animated,
+                id,
+                name,
+            }),
+            (None, Some(name)) if !name.is_empty() => Some(ReactionType::Unicode(name)),
+            _ => None,
+        };
+
+        Ok(Self {
+            id: helper.id,
+            channel_ids: helper.channel_ids,
+            role_ids: helper.role_ids,
+            emoji,
+            title: helper.title,
+            description: helper.description,
+        })
+    }
+}
+
+impl Serialize for OnboardingPromptOption {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let (emoji_id, emoji_name, emoji_animated) = match &self.emoji {
+            Some(ReactionType::Custom {
+                animated,
+                id,
+                name,
+            }) => (Some(id), name.as_deref(), *animated),
+            Some(ReactionType::Unicode(name)) => (None, Some(name.as_str()), false),
+            None => (None, None, false),
+        };
+
+        let mut s = serializer.serialize_struct("OnboardingPromptOption", 8)?;
+        s.serialize_field("id", &self.id)?;
+        s.serialize_field("channel_ids", &self.channel_ids)?;
+        s.serialize_field("role_ids", &self.role_ids)?;
+        s.serialize_field("emoji_id", &emoji_id)?;
+        s.serialize_field("emoji_name", &emoji_name)?;
+        s.serialize_field("emoji_animated", &emoji_animated)?;
+        s.serialize_field("title", &self.title)?;
+        s.serialize_field("description", &self.description)?;
+        s.end()
+    }
+}
+
+enum_number! {
+    /// The type of an [`OnboardingPrompt`].
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object-prompt-types).
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[serde(from = "u8", into = "u8")]
+    #[non_exhaustive]
+    pub enum OnboardingPromptType {
+        /// The options are shown as a list of buttons.
+        #[default]
+        MultipleChoice = 0,
+        /// The options are shown in a dropdown.
+        Dropdown = 1,
+        _ => Unknown(u8),
+    }
+}
+
+enum_number! {
+    /// The criteria used to satisfy the constraints required for enabling onboarding.
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object-onboarding-mode).
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[serde(from = "u8", into = "u8")]
+    #[non_exhaustive]
+    pub enum OnboardingMode {
+        /// Only default channels count towards the constraints.
+        #[default]
+        Default = 0,
+        /// Default channels and questions count towards the constraints.
+        Advanced = 1,
+        _ => Unknown(u8),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{assert_json, from_value, json};
+
+    #[test]
+    fn prompt_option_serde() {
+        let option = OnboardingPromptOption {
+            id: OnboardingPromptOptionId::new(1),
+            channel_ids: vec![ChannelId::new(2)],
+            role_ids: vec![RoleId::new(3)],
+            emoji: Some(ReactionType::Custom {
+                animated: true,
+                id: EmojiId::new(4),
+                name: Some("blob".to_string()),
+            }),
+            title: "Title".to_string(),
+            description: None,
+        };
+
+        assert_json(
+            &option,
+            json!({
+                "id": "1",
+                "channel_ids": ["2"],
+                "role_ids": ["3"],
+                "emoji_id": "4",
+                "emoji_name": "blob",
+                "emoji_animated": true,
+                "title": "Title",
+                "description": null,
+            }),
+        );
+
+        let received: OnboardingPromptOption = from_value(json!({
+            "id": "1",
+            "channel_ids": ["2"],
+            "role_ids": ["3"],
+            "emoji": {"id": "4", "name": "blob", "animated": true},
+            "title": "Title",
+            "description": null,
+        }))
+        .unwrap();
+        assert_eq!(received, option);
+    }
+}
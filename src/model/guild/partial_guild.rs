@@ -2,16 +2,22 @@ use serde::Serialize;
 
 #[cfg(feature = "model")]
 use crate::builder::{
+    AddMember,
+    AuditLogFilter,
     CreateChannel,
     CreateCommand,
+    CreateSoundboardSound,
     CreateSticker,
     EditAutoModRule,
     EditCommandPermissions,
     EditGuild,
+    EditGuildDiscoveryMetadata,
+    EditGuildOnboarding,
     EditGuildWelcomeScreen,
     EditGuildWidget,
     EditMember,
     EditRole,
+    EditSoundboardSound,
     EditSticker,
 };
 #[cfg(all(feature = "cache", feature = "utils", feature = "client"))]
@@ -24,7 +30,7 @@ use crate::gateway::ShardMessenger;
 use crate::http::{CacheHttp, Http, UserPagination};
 use crate::model::prelude::*;
 #[cfg(feature = "model")]
-use crate::model::utils::icon_url;
+use crate::model::utils::{banner_url, discovery_splash_url, icon_url, splash_url};
 use crate::model::utils::{emojis, roles, stickers};
 
 /// Partial information about a [`Guild`]. This does not include information like member data.
@@ -270,6 +276,29 @@ impl PartialGuild {
         self.id.delete_automod_rule(http, rule_id).await
     }
 
+    /// Adds a [`User`] to this guild with a valid OAuth2 access token.
+    ///
+    /// Returns the created [`Member`] object, or nothing if the user is already a member of the
+    /// guild.
+    ///
+    /// **Note**: The access token must have been granted the `guilds.join` scope, and the current
+    /// user must be in the guild with the [Create Instant Invite] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if invalid data is given.
+    ///
+    /// [Create Instant Invite]: Permissions::CREATE_INSTANT_INVITE
+    #[inline]
+    pub async fn add_member(
+        &self,
+        cache_http: impl CacheHttp,
+        user_id: impl Into<UserId>,
+        builder: AddMember,
+    ) -> Result<Option<Member>> {
+        self.id.add_member(cache_http, user_id, builder).await
+    }
+
     /// Ban a [`User`] from the guild, deleting a number of days' worth of messages (`dmd`) between
     /// the range 0 and 7.
     ///
@@ -320,6 +349,27 @@ impl PartialGuild {
         self.id.ban_with_reason(http, user, dmd, reason).await
     }
 
+    /// Ban a [`User`] from the guild, deleting the messages they sent in the last
+    /// `delete_message_seconds` seconds, up to 7 days.
+    ///
+    /// **Note**: Requires the [Ban Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// See [`GuildId::ban_with_message_deletion`].
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    #[inline]
+    pub async fn ban_with_message_deletion(
+        &self,
+        http: impl AsRef<Http>,
+        user: impl Into<UserId>,
+        delete_message_seconds: u32,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        self.id.ban_with_message_deletion(http, user, delete_message_seconds, reason).await
+    }
+
     /// Gets a list of the guild's bans, with additional options and filtering. See
     /// [`Http::get_bans`] for details.
     ///
@@ -362,6 +412,49 @@ impl PartialGuild {
         self.id.audit_logs(http, action_type, user_id, before, limit).await
     }
 
+    /// Gets a list of the guild's audit log entries matching the given filter.
+    ///
+    /// Refer to the documentation for [`AuditLogFilter`] for the available filters.
+    ///
+    /// **Note**: Requires the [View Audit Log] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [View Audit Log]: Permissions::VIEW_AUDIT_LOG
+    #[inline]
+    pub async fn audit_log_entries(
+        &self,
+        http: impl AsRef<Http>,
+        filter: AuditLogFilter,
+    ) -> Result<AuditLogs> {
+        self.id.audit_log_entries(http, filter).await
+    }
+
+    /// Finds the most recent audit log entry of the given action that targets `target_id` and was
+    /// created within the last `within`.
+    ///
+    /// Refer to [`GuildId::find_audit_entry`] for more information.
+    ///
+    /// **Note**: Requires the [View Audit Log] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [View Audit Log]: Permissions::VIEW_AUDIT_LOG
+    #[inline]
+    pub async fn find_audit_entry(
+        &self,
+        http: impl AsRef<Http>,
+        action: audit_log::Action,
+        target_id: impl Into<GenericId>,
+        within: std::time::Duration,
+    ) -> Result<Option<AuditLogEntry>> {
+        self.id.find_audit_entry(http, action, target_id, within).await
+    }
+
     /// Gets all of the guild's channels over the REST API.
     ///
     /// # Errors
@@ -419,6 +512,8 @@ impl PartialGuild {
     ///
     /// # Errors
     ///
+    /// Returns [`ModelError::NestedCategory`] if a category channel is given a parent category.
+    ///
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
     /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
     ///
@@ -638,6 +733,24 @@ impl PartialGuild {
         self.id.create_role(cache_http, builder).await
     }
 
+    /// Creates a new soundboard sound in the guild with the data set.
+    ///
+    /// **Note**: Requires the [Create Guild Expressions] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
+    /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    pub async fn create_soundboard_sound(
+        &self,
+        cache_http: impl CacheHttp,
+        builder: CreateSoundboardSound<'_>,
+    ) -> Result<SoundboardSound> {
+        self.id.create_soundboard_sound(cache_http, builder).await
+    }
+
     /// Creates a new sticker in the guild with the data set, if any.
     ///
     /// **Note**: Requires the [Create Guild Expressions] permission.
@@ -732,6 +845,28 @@ impl PartialGuild {
         self.id.delete_role(http, role_id).await
     }
 
+    /// Deletes a [`SoundboardSound`] by Id from the guild.
+    ///
+    /// **Note**: If the sound was created by the current user, requires either the [Create Guild
+    /// Expressions] or the [Manage Guild Expressions] permission. Otherwise, the [Manage Guild
+    /// Expressions] permission is required.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if a sound with that id
+    /// does not exist.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    /// [Manage Guild Expressions]: Permissions::MANAGE_GUILD_EXPRESSIONS
+    #[inline]
+    pub async fn delete_soundboard_sound(
+        &self,
+        http: impl AsRef<Http>,
+        sound_id: impl Into<SoundId>,
+    ) -> Result<()> {
+        self.id.delete_soundboard_sound(http, sound_id).await
+    }
+
     /// Deletes a [`Sticker`] by Id from the guild.
     ///
     /// **Note**: If the sticker was created by the current user, requires either the [Create Guild
@@ -862,8 +997,9 @@ impl PartialGuild {
         &self,
         http: impl AsRef<Http>,
         new_nickname: Option<&str>,
+        audit_log_reason: Option<&str>,
     ) -> Result<()> {
-        self.id.edit_nickname(http, new_nickname).await
+        self.id.edit_nickname(http, new_nickname, audit_log_reason).await
     }
 
     /// Edits a role, optionally setting its fields.
@@ -916,6 +1052,78 @@ impl PartialGuild {
         self.id.edit_role_position(http, role_id, position).await
     }
 
+    /// Edits the positions of multiple [`Role`]s in a single request, updating the positions of
+    /// the roles stored in [`Self::roles`] from the response.
+    ///
+    /// Refer to [`GuildId::edit_role_positions`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user lacks permission, or if a role would be
+    /// moved to or from a position above the current user's highest role.
+    ///
+    /// [Manage Roles]: Permissions::MANAGE_ROLES
+    pub async fn edit_role_positions(
+        &mut self,
+        http: impl AsRef<Http>,
+        positions: impl IntoIterator<Item = (RoleId, u16)>,
+        reason: Option<&str>,
+    ) -> Result<Vec<Role>> {
+        let roles = self.id.edit_role_positions(http, positions, reason).await?;
+
+        for role in &roles {
+            if let Some(existing) = self.roles.get_mut(&role.id) {
+                existing.position = role.position;
+            }
+        }
+
+        Ok(roles)
+    }
+
+    /// Edits a soundboard sound.
+    ///
+    /// **Note**: If the sound was created by the current user, requires either the [Create Guild
+    /// Expressions] or the [Manage Guild Expressions] permission. Otherwise, the [Manage Guild
+    /// Expressions] permission is required.
+    ///
+    /// # Examples
+    ///
+    /// Lower the volume of a sound:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::guild::PartialGuild;
+    /// # use serenity::model::id::GuildId;
+    /// use serenity::builder::EditSoundboardSound;
+    /// use serenity::model::id::SoundId;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http: Http = unimplemented!();
+    /// # let guild = PartialGuild::get(&http, GuildId::new(7)).await?;
+    /// let builder = EditSoundboardSound::new().volume(0.5);
+    /// guild.edit_soundboard_sound(&http, SoundId::new(7), builder).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if invalid data is given.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    /// [Manage Guild Expressions]: Permissions::MANAGE_GUILD_EXPRESSIONS
+    #[inline]
+    pub async fn edit_soundboard_sound(
+        &self,
+        cache_http: impl CacheHttp,
+        sound_id: impl Into<SoundId>,
+        builder: EditSoundboardSound<'_>,
+    ) -> Result<SoundboardSound> {
+        self.id.edit_soundboard_sound(cache_http, sound_id, builder).await
+    }
+
     /// Edits a sticker.
     ///
     /// **Note**: If the sticker was created by the current user, requires either the [Create Guild
@@ -958,6 +1166,53 @@ impl PartialGuild {
         self.id.edit_sticker(cache_http, sticker_id, builder).await
     }
 
+    /// Gets the guild's discovery metadata, such as its discovery category and search keywords.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ModelError::MissingGuildFeature`] if the guild does not have the `DISCOVERABLE`
+    /// feature.
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn discovery_metadata(
+        &self,
+        http: impl AsRef<Http>,
+    ) -> Result<GuildDiscoveryMetadata> {
+        if !self.has_feature("DISCOVERABLE") {
+            return Err(Error::Model(ModelError::MissingGuildFeature("DISCOVERABLE")));
+        }
+
+        self.id.discovery_metadata(http).await
+    }
+
+    /// Edits the guild's discovery metadata.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ModelError::MissingGuildFeature`] if the guild does not have the `DISCOVERABLE`
+    /// feature.
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn edit_discovery_metadata(
+        &self,
+        cache_http: impl CacheHttp,
+        builder: EditGuildDiscoveryMetadata<'_>,
+    ) -> Result<GuildDiscoveryMetadata> {
+        if !self.has_feature("DISCOVERABLE") {
+            return Err(Error::Model(ModelError::MissingGuildFeature("DISCOVERABLE")));
+        }
+
+        self.id.edit_discovery_metadata(cache_http, builder).await
+    }
+
     /// Edits the guild's welcome screen.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
@@ -975,6 +1230,34 @@ impl PartialGuild {
         self.id.edit_welcome_screen(cache_http, builder).await
     }
 
+    /// Edits the guild's onboarding flow.
+    ///
+    /// **Note**: Requires the [Manage Guild] and [Manage Roles] permissions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the resulting flow
+    /// does not satisfy Discord's onboarding constraints.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    /// [Manage Roles]: Permissions::MANAGE_ROLES
+    pub async fn edit_onboarding(
+        &self,
+        cache_http: impl CacheHttp,
+        builder: EditGuildOnboarding<'_>,
+    ) -> Result<GuildOnboarding> {
+        self.id.edit_onboarding(cache_http, builder).await
+    }
+
+    /// Gets the guild's onboarding flow.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user is not in the guild.
+    pub async fn onboarding(&self, http: impl AsRef<Http>) -> Result<GuildOnboarding> {
+        self.id.onboarding(http).await
+    }
+
     /// Edits the guild's widget.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
@@ -1032,6 +1315,70 @@ impl PartialGuild {
         guild.greater_member_hierarchy(cache, lhs_id, rhs_id)
     }
 
+    /// Gets the highest role a [`Member`] of this Guild has.
+    ///
+    /// Returns None if the member has no roles or the member from this guild.
+    ///
+    /// See [`Guild::member_highest_role`].
+    #[must_use]
+    pub fn member_highest_role(&self, member: &Member) -> Option<&Role> {
+        Guild::member_highest_role_(&self.roles, member)
+    }
+
+    /// Returns the roles of this guild that the given [`Member`] is able to assign to others.
+    ///
+    /// See [`Guild::roles_assignable_by`].
+    pub fn roles_assignable_by<'a>(&'a self, actor: &Member) -> impl Iterator<Item = &'a Role> {
+        Guild::roles_assignable_by_(&self.roles, self.id, self.owner_id, actor)
+    }
+
+    /// Returns the roles of the guild sorted by their rank in the role hierarchy, from lowest to
+    /// highest.
+    ///
+    /// See [`Guild::roles_sorted`].
+    #[must_use]
+    pub fn roles_sorted(&self) -> Vec<&Role> {
+        Guild::roles_sorted_(&self.roles)
+    }
+
+    /// Returns the `@everyone` role of the guild, which shares its Id with the guild.
+    ///
+    /// Returns [`None`] if the role is not present in [`Self::roles`].
+    #[must_use]
+    pub fn everyone_role(&self) -> Option<&Role> {
+        Guild::everyone_role_(&self.roles, self.id)
+    }
+
+    /// Returns whether the guild has the given feature enabled.
+    ///
+    /// See [`Guild::has_feature`].
+    #[must_use]
+    pub fn has_feature(&self, feature: &str) -> bool {
+        Guild::has_feature_(&self.features, feature)
+    }
+
+    /// Gets all roles in the guild that are managed by a bot or an integration.
+    ///
+    /// See [`Guild::integration_roles`].
+    pub fn integration_roles(&self) -> impl Iterator<Item = &Role> {
+        Guild::integration_roles_(&self.roles)
+    }
+
+    /// Returns an iterator over the guild's emojis that are restricted to the given role.
+    ///
+    /// See [`Guild::emojis_for_role`].
+    pub fn emojis_for_role(&self, role_id: RoleId) -> impl Iterator<Item = &Emoji> {
+        Guild::emojis_for_role_(&self.emojis, role_id)
+    }
+
+    /// Resolves a role mention, such as `<@&136107769680887808>`, to a role of this guild.
+    ///
+    /// See [`Guild::resolve_role_mention`].
+    #[must_use]
+    pub fn resolve_role_mention(&self, mention: &str) -> Option<&Role> {
+        Guild::resolve_role_mention_(&self.roles, mention)
+    }
+
     /// Calculate a [`Member`]'s permissions in the guild.
     #[inline]
     #[must_use]
@@ -1131,7 +1478,8 @@ impl PartialGuild {
     ///
     /// # Errors
     ///
-    /// Returns an [`Error::Http`] if the API returns an error.
+    /// Returns [`Error::NotInRange`] if `limit` is not between 1 and 1000. Otherwise returns an
+    /// [`Error::Http`] if the API returns an error.
     #[inline]
     pub async fn search_members(
         &self,
@@ -1165,6 +1513,31 @@ impl PartialGuild {
         self.id.start_prune(cache_http.http(), days).await
     }
 
+    /// Starts a prune of [`Member`]s, with additional options.
+    ///
+    /// Refer to [`GuildId::start_prune_with_options`] for more information.
+    ///
+    /// **Note**: Requires [Kick Members] and [Manage Guild] permissions.
+    ///
+    /// # Errors
+    ///
+    /// See [`GuildId::start_prune_with_options`].
+    ///
+    /// [Kick Members]: Permissions::KICK_MEMBERS
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn start_prune_with_options(
+        &self,
+        cache_http: impl CacheHttp,
+        days: u8,
+        include_roles: &[RoleId],
+        compute_count: bool,
+        reason: Option<&str>,
+    ) -> Result<GuildPrune> {
+        self.id
+            .start_prune_with_options(cache_http.http(), days, include_roles, compute_count, reason)
+            .await
+    }
+
     /// Kicks a [`Member`] from the guild.
     ///
     /// Requires the [Kick Members] permission.
@@ -1196,13 +1569,31 @@ impl PartialGuild {
     /// Returns a formatted URL of the guild's icon, if the guild has an icon.
     #[must_use]
     pub fn icon_url(&self) -> Option<String> {
-        icon_url(self.id, self.icon.as_ref())
+        icon_url(self.id, self.icon.as_ref(), None, None)
+    }
+
+    /// Returns the formatted URL of the guild's icon in the given format and size, if one exists.
+    ///
+    /// If no format is given, this behaves like [`Self::icon_url`]. See [`ImageFormat`] for
+    /// how the size is rounded.
+    #[must_use]
+    pub fn icon_url_sized(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        icon_url(self.id, self.icon.as_ref(), format, Some(size))
     }
 
     /// Returns a formatted URL of the guild's banner, if the guild has a banner.
     #[must_use]
     pub fn banner_url(&self) -> Option<String> {
-        self.banner.as_ref().map(|banner| cdn!("/banners/{}/{}.webp", self.id, banner))
+        banner_url(self.id, self.banner.as_deref(), None, 1024)
+    }
+
+    /// Returns the formatted URL of the guild's banner in the given format and size, if one exists.
+    ///
+    /// If no format is given, this behaves like [`Self::banner_url`]. See [`ImageFormat`] for
+    /// how the size is rounded.
+    #[must_use]
+    pub fn banner_url_sized(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        banner_url(self.id, self.banner.as_deref(), format, size)
     }
 
     /// Gets all [`Emoji`]s of this guild via HTTP.
@@ -1225,6 +1616,123 @@ impl PartialGuild {
         self.id.emoji(http, emoji_id).await
     }
 
+    /// Gets all [`Sticker`]s of this guild via HTTP.
+    ///
+    /// Unlike the `stickers` field, which is a snapshot taken when the guild was received, this
+    /// always reflects the current state of the guild.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the guild is unavailable.
+    #[inline]
+    pub async fn stickers(&self, http: impl AsRef<Http>) -> Result<Vec<Sticker>> {
+        self.id.stickers(http).await
+    }
+
+    /// Gets a [`Sticker`] of this guild by its Id via HTTP.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if a sticker with that Id does not exist in the guild.
+    #[inline]
+    pub async fn sticker(&self, http: impl AsRef<Http>, sticker_id: StickerId) -> Result<Sticker> {
+        self.id.sticker(http, sticker_id).await
+    }
+
+    /// Gets all [`GuildTemplate`]s of this guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn templates(&self, http: impl AsRef<Http>) -> Result<Vec<GuildTemplate>> {
+        self.id.templates(http).await
+    }
+
+    /// Creates a [`GuildTemplate`] from the guild's current state.
+    ///
+    /// Refer to [`GuildId::create_template`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user lacks permission, or if invalid data is
+    /// given.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn create_template(
+        &self,
+        http: impl AsRef<Http>,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<GuildTemplate> {
+        self.id.create_template(http, name, description).await
+    }
+
+    /// Syncs a [`GuildTemplate`] to the guild's current state.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user lacks permission, or if no template with
+    /// the given code exists for the guild.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn sync_template(&self, http: impl AsRef<Http>, code: &str) -> Result<GuildTemplate> {
+        self.id.sync_template(http, code).await
+    }
+
+    /// Deletes a [`GuildTemplate`], returning the deleted template.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user lacks permission, or if no template with
+    /// the given code exists for the guild.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn delete_template(
+        &self,
+        http: impl AsRef<Http>,
+        code: &str,
+    ) -> Result<GuildTemplate> {
+        self.id.delete_template(http, code).await
+    }
+
+    /// Gets all [`SoundboardSound`]s of this guild via HTTP.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the guild is unavailable.
+    #[inline]
+    pub async fn soundboard_sounds(&self, http: impl AsRef<Http>) -> Result<Vec<SoundboardSound>> {
+        self.id.soundboard_sounds(http).await
+    }
+
+    /// Gets a [`SoundboardSound`] of this guild by its ID via HTTP.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if a sound with the given Id does not exist for the guild.
+    #[inline]
+    pub async fn soundboard_sound(
+        &self,
+        http: impl AsRef<Http>,
+        sound_id: SoundId,
+    ) -> Result<SoundboardSound> {
+        self.id.soundboard_sound(http, sound_id).await
+    }
+
     /// Gets all integration of the guild.
     ///
     /// Requires the [Manage Guild] permission.
@@ -1335,6 +1843,70 @@ impl PartialGuild {
         self.id.move_member(cache_http, user_id, channel_id).await
     }
 
+    /// Times out a member until the given time, preventing them from sending messages, reacting,
+    /// or joining voice channels.
+    ///
+    /// **Note**: Requires the [Moderate Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// See [`GuildId::timeout_member`].
+    ///
+    /// [Moderate Members]: Permissions::MODERATE_MEMBERS
+    #[doc(alias = "disable_communication_until")]
+    pub async fn timeout_member(
+        &self,
+        cache_http: impl CacheHttp,
+        user_id: impl Into<UserId>,
+        until: Timestamp,
+        reason: Option<&str>,
+    ) -> Result<Member> {
+        self.id.timeout_member(cache_http, user_id, until, reason).await
+    }
+
+    /// Removes the timeout of a member, if they have one.
+    ///
+    /// **Note**: Requires the [Moderate Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Moderate Members]: Permissions::MODERATE_MEMBERS
+    #[doc(alias = "enable_communication")]
+    pub async fn remove_timeout(
+        &self,
+        cache_http: impl CacheHttp,
+        user_id: impl Into<UserId>,
+        reason: Option<&str>,
+    ) -> Result<Member> {
+        self.id.remove_timeout(cache_http, user_id, reason).await
+    }
+
+    /// Moves a member to the given voice or stage channel, or disconnects them from voice if
+    /// `channel_id` is [`None`].
+    ///
+    /// **Note**: Requires the [Move Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns [`ModelError::InvalidChannelType`] if the target channel
+    /// is cached and is neither a voice nor a stage channel.
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the member is not
+    /// currently in a voice channel for this guild.
+    ///
+    /// [Move Members]: Permissions::MOVE_MEMBERS
+    pub async fn move_member_to_voice(
+        &self,
+        cache_http: impl CacheHttp,
+        user_id: impl Into<UserId>,
+        channel_id: Option<ChannelId>,
+        reason: Option<&str>,
+    ) -> Result<Member> {
+        self.id.move_member_to_voice(cache_http, user_id, channel_id, reason).await
+    }
+
     /// Calculate a [`Member`]'s permissions in a given channel in the guild.
     #[inline]
     #[must_use]
@@ -1421,7 +1993,37 @@ impl PartialGuild {
     #[inline]
     #[must_use]
     pub fn splash_url(&self) -> Option<String> {
-        self.splash.as_ref().map(|splash| cdn!("/splashes/{}/{}.webp?size=4096", self.id, splash))
+        splash_url(self.id, self.splash.as_ref(), Some(ImageFormat::WebP), 4096)
+    }
+
+    /// Returns the formatted URL of the guild's splash image in the given format and size, if one
+    /// exists.
+    ///
+    /// If no format is given, this behaves like [`Self::splash_url`]. See [`ImageFormat`] for
+    /// how the size is rounded.
+    #[must_use]
+    pub fn splash_url_sized(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        splash_url(self.id, self.splash.as_ref(), format, size)
+    }
+
+    /// Returns the formatted URL of the guild's discovery splash image, if one exists.
+    #[must_use]
+    pub fn discovery_splash_url(&self) -> Option<String> {
+        discovery_splash_url(self.id, self.discovery_splash.as_ref(), Some(ImageFormat::WebP), 4096)
+    }
+
+    /// Returns the formatted URL of the guild's discovery splash image in the given format and
+    /// size, if one exists.
+    ///
+    /// If no format is given, this behaves like [`Self::discovery_splash_url`]. See
+    /// [`ImageFormat`] for how the size is rounded.
+    #[must_use]
+    pub fn discovery_splash_url_sized(
+        &self,
+        format: Option<ImageFormat>,
+        size: u16,
+    ) -> Option<String> {
+        discovery_splash_url(self.id, self.discovery_splash.as_ref(), format, size)
     }
 
     /// Starts an integration sync for the given integration Id.
@@ -1458,6 +2060,20 @@ impl PartialGuild {
         self.id.unban(http, user_id).await
     }
 
+    /// # Errors
+    ///
+    /// In addition to the reasons [`Self::unban`] may return an error, may also return an error if
+    /// the reason is too long.
+    #[inline]
+    pub async fn unban_with_reason(
+        &self,
+        http: impl AsRef<Http>,
+        user_id: impl Into<UserId>,
+        reason: &str,
+    ) -> Result<()> {
+        self.id.unban_with_reason(http, user_id, reason).await
+    }
+
     /// Retrieve's the guild's vanity URL.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
@@ -1473,7 +2089,28 @@ impl PartialGuild {
         self.id.vanity_url(http).await
     }
 
-    /// Retrieves the guild's webhooks.
+    /// Retrieves the guild's vanity invite code along with its number of uses.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ModelError::MissingGuildFeature`] if the guild does not have the `VANITY_URL`
+    /// feature.
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn vanity_invite(&self, http: impl AsRef<Http>) -> Result<VanityInviteInfo> {
+        if !self.has_feature("VANITY_URL") {
+            return Err(Error::Model(ModelError::MissingGuildFeature("VANITY_URL")));
+        }
+
+        self.id.vanity_invite(http).await
+    }
+
+    /// Retrieves the webhooks of every channel in the guild. To only list the webhooks of a single
+    /// channel, use [`GuildChannel::webhooks`].
     ///
     /// **Note**: Requires the [Manage Webhooks] permission.
     ///
@@ -10,6 +10,8 @@ use crate::http::Http;
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
+#[cfg(feature = "model")]
+use crate::model::utils::cdn_asset_url;
 use crate::model::utils::is_false;
 
 fn minus1_as_0<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<u16, D::Error> {
@@ -92,6 +94,22 @@ impl Role {
         http.as_ref().delete_role(self.guild_id, self.id, None).await
     }
 
+    /// Deletes the role, with a reason that will appear in the audit log.
+    ///
+    /// Refer to [`Self::delete`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors [`Self::delete`] may return, can return
+    /// [`Error::ExceededLimit`] if the length of the reason is greater than 512.
+    pub async fn delete_with_reason(&self, http: impl AsRef<Http>, reason: &str) -> Result<()> {
+        if reason.chars().count() > 512 {
+            return Err(Error::ExceededLimit(reason.to_string(), 512));
+        }
+
+        http.as_ref().delete_role(self.guild_id, self.id, Some(reason)).await
+    }
+
     /// Edits a [`Role`], optionally setting its new fields.
     ///
     /// Requires the [Manage Roles] permission.
@@ -133,15 +151,69 @@ impl Role {
         }
     }
 
+    /// Whether this is the guild's premium subscriber (booster) role.
+    #[inline]
+    #[must_use]
+    pub fn is_premium_subscriber(&self) -> bool {
+        self.tags.premium_subscriber
+    }
+
+    /// Whether this role is available for purchase through a role subscription.
+    #[inline]
+    #[must_use]
+    pub fn is_available_for_purchase(&self) -> bool {
+        self.tags.available_for_purchase
+    }
+
+    /// Whether this is one of the guild's linked roles.
+    #[inline]
+    #[must_use]
+    pub fn is_guild_connection(&self) -> bool {
+        self.tags.guild_connections
+    }
+
+    /// Whether this role is managed by a bot, i.e. it was created when the bot was added to the
+    /// guild.
+    #[inline]
+    #[must_use]
+    pub fn is_bot_managed(&self) -> bool {
+        self.tags.bot_id.is_some()
+    }
+
+    /// The Id of the bot this role belongs to, if any.
+    #[inline]
+    #[must_use]
+    pub fn bot_id(&self) -> Option<UserId> {
+        self.tags.bot_id
+    }
+
+    /// The Id of the integration this role belongs to, if any.
+    #[inline]
+    #[must_use]
+    pub fn integration_id(&self) -> Option<IntegrationId> {
+        self.tags.integration_id
+    }
+
     #[inline]
     #[must_use]
     /// Generates a URL to the Role icon's image.
     pub fn icon_url(&self) -> Option<String> {
-        self.icon.map(|icon| {
-            let ext = if icon.is_animated() { "gif" } else { "webp" };
+        self.icon_url_with(None, None)
+    }
+
+    /// Generates a URL to the Role icon's image in the given format and size.
+    ///
+    /// If no format is given, this behaves like [`Self::icon_url`]. See [`ImageFormat`] for
+    /// how the size is rounded.
+    #[inline]
+    #[must_use]
+    pub fn icon_url_sized(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        self.icon_url_with(format, Some(size))
+    }
 
-            cdn!("/role-icons/{}/{}.{}", self.id, icon, ext)
-        })
+    fn icon_url_with(&self, format: Option<ImageFormat>, size: Option<u16>) -> Option<String> {
+        let path = format_args!("role-icons/{}", self.id);
+        self.icon.as_ref().map(|icon| cdn_asset_url(path, icon, format, size))
     }
 }
 
@@ -311,4 +383,35 @@ mod tests {
             json!({"bot_id": null, "integration_id": null, "subscription_listing_id": null}),
         );
     }
+
+    #[test]
+    #[cfg(feature = "model")]
+    fn role_tags_predicates() {
+        use super::Role;
+        use crate::json::from_value;
+        use crate::model::id::UserId;
+
+        let role: Role = from_value(json!({
+            "id": "1",
+            "color": 0,
+            "hoist": false,
+            "managed": true,
+            "name": "bot",
+            "permissions": "0",
+            "position": 1,
+            "tags": {
+                "bot_id": "2",
+                "premium_subscriber": null,
+                "guild_connections": null,
+            },
+        }))
+        .unwrap();
+
+        assert!(role.is_premium_subscriber());
+        assert!(role.is_guild_connection());
+        assert!(!role.is_available_for_purchase());
+        assert!(role.is_bot_managed());
+        assert_eq!(role.bot_id(), Some(UserId::new(2)));
+        assert_eq!(role.integration_id(), None);
+    }
 }
@@ -1,4 +1,10 @@
+#[cfg(feature = "model")]
+use crate::http::Http;
+#[cfg(feature = "model")]
+use crate::internal::prelude::*;
 use crate::model::prelude::*;
+#[cfg(feature = "model")]
+use crate::model::utils::cdn_asset_url;
 
 /// Information about a guild scheduled event.
 ///
@@ -53,6 +59,48 @@ pub struct ScheduledEvent {
     pub image: Option<ImageHash>,
 }
 
+#[cfg(feature = "model")]
+impl ScheduledEvent {
+    /// Returns the formatted URL of the event's cover image, if one exists.
+    #[must_use]
+    pub fn cover_url(&self) -> Option<String> {
+        self.cover_url_sized(Some(ImageFormat::WebP), 1024)
+    }
+
+    /// Returns the formatted URL of the event's cover image in the given format and size, if one
+    /// exists.
+    ///
+    /// See [`ImageFormat`] for how the size is rounded.
+    #[must_use]
+    pub fn cover_url_sized(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        let path = format_args!("guild-events/{}", self.id);
+        self.image.as_ref().map(|image| cdn_asset_url(path, image, format, Some(size)))
+    }
+
+    /// Fetches the users interested in the event, i.e. who have RSVP'd to it, along with their
+    /// [`Member`] objects if `with_member` is `true`.
+    ///
+    /// Only the first 100 users are returned. To paginate, see
+    /// [`GuildId::scheduled_event_users_optioned`].
+    ///
+    /// **Note**: Requires the [View Channel] permission for the channel associated with the event.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [View Channel]: Permissions::VIEW_CHANNEL
+    pub async fn users(
+        &self,
+        http: impl AsRef<Http>,
+        with_member: bool,
+    ) -> Result<Vec<ScheduledEventUser>> {
+        self.guild_id
+            .scheduled_event_users_optioned(http, self.id, None, None, Some(with_member))
+            .await
+    }
+}
+
 enum_number! {
     /// [Discord docs](https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-status).
     #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
@@ -115,3 +163,34 @@ enum_number! {
         _ => Unknown(u8),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(feature = "model")]
+    fn cover_url() {
+        use super::*;
+        use crate::json::{from_value, json};
+
+        let mut event: ScheduledEvent = from_value(json!({
+            "id": "1",
+            "guild_id": "2",
+            "channel_id": null,
+            "name": "Event",
+            "scheduled_start_time": "2021-01-01T00:00:00Z",
+            "scheduled_end_time": null,
+            "privacy_level": 2,
+            "status": 1,
+            "entity_type": 3,
+            "entity_id": null,
+        }))
+        .unwrap();
+        assert_eq!(event.cover_url(), None);
+
+        event.image = Some("f1eff024d9c85339c877985229ed8fee".parse().unwrap());
+        assert_eq!(
+            event.cover_url().unwrap(),
+            "https://cdn.discordapp.com/guild-events/1/f1eff024d9c85339c877985229ed8fee.webp?size=1024"
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+/// A snapshot of a guild's settings, channels and roles, which can be used to create new guilds.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild-template#guild-template-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildTemplate {
+    /// The unique code of the template.
+    pub code: String,
+    /// The name of the template.
+    pub name: String,
+    /// The description of the template.
+    pub description: Option<String>,
+    /// The number of times the template has been used to create a guild.
+    pub usage_count: u64,
+    /// The Id of the user who created the template.
+    pub creator_id: UserId,
+    /// The user who created the template.
+    pub creator: User,
+    /// When the template was created.
+    pub created_at: Timestamp,
+    /// When the template was last synced to the source guild.
+    pub updated_at: Timestamp,
+    /// The Id of the guild the template is based on.
+    pub source_guild_id: GuildId,
+    /// The snapshot of the source guild the template was created from.
+    ///
+    /// This is a partial guild object, containing only the data that is copied to new guilds.
+    pub serialized_source_guild: Value,
+    /// Whether the template has unsynced changes, i.e. the source guild changed since the last
+    /// sync.
+    pub is_dirty: Option<bool>,
+}
+
+#[cfg(feature = "model")]
+impl GuildTemplate {
+    /// Returns the URL that can be used to create a new guild from the template.
+    #[must_use]
+    pub fn url(&self) -> String {
+        format!("https://discord.new/{}", self.code)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn deserialize_template() {
+        let template: GuildTemplate = from_value(json!({
+            "code": "hgM48av5Q69A",
+            "name": "Friends & Family",
+            "description": null,
+            "usage_count": 49_605,
+            "creator_id": "132837293881950208",
+            "creator": {
+                "id": "132837293881950208",
+                "username": "hoges",
+                "avatar": null,
+                "discriminator": "0"
+            },
+            "created_at": "2020-04-02T21:10:38+00:00",
+            "updated_at": "2020-05-01T17:57:38+00:00",
+            "source_guild_id": "678070694164299796",
+            "serialized_source_guild": {
+                "name": "Friends & Family",
+                "roles": [],
+                "channels": []
+            },
+            "is_dirty": null
+        }))
+        .unwrap();
+
+        assert_eq!(template.source_guild_id, GuildId::new(678_070_694_164_299_796));
+        assert_eq!(template.serialized_source_guild["name"], "Friends & Family");
+        assert!(template.is_dirty.is_none());
+    }
+}
@@ -189,6 +189,7 @@ id_u64! {
     MessageId: "An identifier for a Message";
     RoleId: "An identifier for a Role";
     ScheduledEventId: "An identifier for a Scheduled Event";
+    SoundId: "An identifier for a soundboard sound.";
     StickerId: "An identifier for a sticker.";
     StickerPackId: "An identifier for a sticker pack.";
     StickerPackBannerId: "An identifier for a sticker pack banner.";
@@ -205,6 +206,8 @@ id_u64! {
     RuleId: "An identifier for an auto moderation rule";
     ForumTagId: "An identifier for a forum tag.";
     EntitlementId: "An identifier for an entitlement.";
+    OnboardingPromptId: "An identifier for an onboarding prompt.";
+    OnboardingPromptOptionId: "An identifier for an onboarding prompt option.";
 }
 
 /// An identifier for a Shard.
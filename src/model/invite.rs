@@ -386,6 +386,21 @@ impl RichInvite {
     pub fn url(&self) -> String {
         format!("https://discord.gg/{}", self.code)
     }
+
+    /// Returns when the invite expires, computed from [`Self::created_at`] and
+    /// [`Self::max_age`].
+    ///
+    /// Returns [`None`] if the invite never expires by age. It may still expire by reaching
+    /// [`Self::max_uses`].
+    #[must_use]
+    pub fn expires_at(&self) -> Option<Timestamp> {
+        if self.max_age == 0 {
+            return None;
+        }
+
+        let secs = self.created_at.unix_timestamp() + i64::from(self.max_age);
+        Timestamp::from_unix_timestamp(secs).ok()
+    }
 }
 
 /// [Discord docs](https://discord.com/developers/docs/resources/invite#invite-stage-instance-object).
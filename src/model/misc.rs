@@ -11,6 +11,8 @@ use std::str::FromStr;
 use arrayvec::ArrayString;
 
 use super::prelude::*;
+#[cfg(all(feature = "model", feature = "utils"))]
+use super::utils::emoji_url;
 #[cfg(all(feature = "model", any(feature = "cache", feature = "utils")))]
 use crate::utils;
 
@@ -158,6 +160,36 @@ impl std::str::FromStr for ImageHash {
     }
 }
 
+/// The file format of an image requested from the Discord CDN.
+///
+/// The `*_url_sized` methods which take a format also take the size of the image in pixels.
+/// Discord only accepts powers of two between 16 and 4096, so the size is rounded up to the next
+/// power of two within that range.
+///
+/// [Discord docs](https://discord.com/developers/docs/reference#image-formatting-image-formats).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ImageFormat {
+    Png,
+    Jpeg,
+    WebP,
+    /// **Note**: Only available for animated images.
+    Gif,
+}
+
+impl ImageFormat {
+    /// Returns the file extension used for this format.
+    #[must_use]
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg => "jpg",
+            Self::WebP => "webp",
+            Self::Gif => "gif",
+        }
+    }
+}
+
 /// A version of an emoji used only when solely the animated state, Id, and name are known.
 ///
 /// [Discord docs](https://discord.com/developers/docs/topics/gateway#activity-object-activity-emoji).
@@ -178,9 +210,17 @@ impl EmojiIdentifier {
     /// Generates a URL to the emoji's image.
     #[must_use]
     pub fn url(&self) -> String {
-        let ext = if self.animated { "gif" } else { "png" };
+        let format = if self.animated { ImageFormat::Gif } else { ImageFormat::Png };
+        emoji_url(self.id, self.animated, Some(format), None)
+    }
 
-        cdn!("/emojis/{}.{}", self.id, ext)
+    /// Generates a URL to the emoji's image in the given format and size.
+    ///
+    /// If no format is given, GIF is used for animated emojis and WEBP otherwise. See
+    /// [`ImageFormat`] for how the size is rounded.
+    #[must_use]
+    pub fn url_sized(&self, format: Option<ImageFormat>, size: u16) -> String {
+        emoji_url(self.id, self.animated, format, Some(size))
     }
 }
 
@@ -0,0 +1,73 @@
+//! Models for guild soundboard sounds.
+
+use crate::model::prelude::*;
+
+/// A sound that can be played in voice channels through the soundboard.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/soundboard#soundboard-sound-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct SoundboardSound {
+    /// The name of the sound.
+    pub name: String,
+    /// The Id of the sound.
+    pub sound_id: SoundId,
+    /// The volume of the sound, from 0 to 1.
+    pub volume: f64,
+    /// The Id of the sound's custom emoji, if it has one.
+    pub emoji_id: Option<EmojiId>,
+    /// The unicode character of the sound's standard emoji, if it has one.
+    pub emoji_name: Option<String>,
+    /// The Id of the guild the sound is in. [`None`] for default sounds.
+    pub guild_id: Option<GuildId>,
+    /// Whether the sound can be used. May be `false` due to loss of server boosts.
+    pub available: bool,
+    /// The user who created the sound.
+    ///
+    /// **Note**: Only present when fetched with the [Create Guild Expressions] or [Manage Guild
+    /// Expressions] permission.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    /// [Manage Guild Expressions]: Permissions::MANAGE_GUILD_EXPRESSIONS
+    pub user: Option<User>,
+}
+
+impl SoundboardSound {
+    /// Returns the sound's emoji, if it has one.
+    #[must_use]
+    pub fn emoji(&self) -> Option<ReactionType> {
+        match (self.emoji_id, &self.emoji_name) {
+            (Some(id), name) => Some(ReactionType::Custom {
+                animated: false,
+                id,
+                name: name.clone(),
+            }),
+            (None, Some(name)) => Some(ReactionType::Unicode(name.clone())),
+            (None, None) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn sound_emoji() {
+        let sound: SoundboardSound = from_value(json!({
+            "name": "quack",
+            "sound_id": "1",
+            "volume": 1.0,
+            "emoji_id": null,
+            "emoji_name": "\u{1f986}",
+            "guild_id": "2",
+            "available": true,
+        }))
+        .unwrap();
+
+        assert!(sound.user.is_none());
+        assert_eq!(sound.emoji(), Some(ReactionType::Unicode("\u{1f986}".to_string())));
+    }
+}
@@ -102,6 +102,9 @@ impl StickerItem {
 
     /// Retrieves the URL to the sticker image.
     ///
+    /// The extension depends on the [`StickerFormatType`]: PNG and APNG stickers use `.png`,
+    /// Lottie stickers use `.json`, and GIF stickers use `.gif` on the media proxy.
+    ///
     /// **Note**: This will only be `None` if the format_type is unknown.
     #[inline]
     #[must_use]
@@ -262,6 +265,9 @@ impl Sticker {
 
     /// Retrieves the URL to the sticker image.
     ///
+    /// The extension depends on the [`StickerFormatType`]: PNG and APNG stickers use `.png`,
+    /// Lottie stickers use `.json`, and GIF stickers use `.gif` on the media proxy.
+    ///
     /// **Note**: This will only be `None` if the format_type is unknown.
     #[inline]
     #[must_use]
@@ -313,9 +319,40 @@ fn sticker_url(sticker_id: StickerId, sticker_format_type: StickerFormatType) ->
     let ext = match sticker_format_type {
         StickerFormatType::Png | StickerFormatType::Apng => "png",
         StickerFormatType::Lottie => "json",
-        StickerFormatType::Gif => "gif",
+        // GIF stickers are only served by the media proxy, not the CDN.
+        StickerFormatType::Gif => return Some(media!("/stickers/{}.gif", sticker_id)),
         StickerFormatType::Unknown(_) => return None,
     };
 
     Some(cdn!("/stickers/{}.{}", sticker_id, ext))
 }
+
+#[cfg(test)]
+#[cfg(feature = "model")]
+mod test {
+    use super::sticker_url;
+    use crate::model::prelude::*;
+
+    #[test]
+    fn test_sticker_url() {
+        let id = StickerId::new(1);
+
+        assert_eq!(
+            sticker_url(id, StickerFormatType::Png).unwrap(),
+            "https://cdn.discordapp.com/stickers/1.png"
+        );
+        assert_eq!(
+            sticker_url(id, StickerFormatType::Apng).unwrap(),
+            "https://cdn.discordapp.com/stickers/1.png"
+        );
+        assert_eq!(
+            sticker_url(id, StickerFormatType::Lottie).unwrap(),
+            "https://cdn.discordapp.com/stickers/1.json"
+        );
+        assert_eq!(
+            sticker_url(id, StickerFormatType::Gif).unwrap(),
+            "https://media.discordapp.net/stickers/1.gif"
+        );
+        assert!(sticker_url(id, StickerFormatType::Unknown(5)).is_none());
+    }
+}
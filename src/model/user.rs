@@ -24,7 +24,7 @@ use crate::internal::prelude::*;
 #[cfg(feature = "model")]
 use crate::json::json;
 #[cfg(feature = "model")]
-use crate::model::utils::avatar_url;
+use crate::model::utils::{avatar_url, cdn_asset_url};
 
 /// Used with `#[serde(with|deserialize_with|serialize_with)]`
 ///
@@ -379,7 +379,17 @@ impl User {
     #[inline]
     #[must_use]
     pub fn avatar_url(&self) -> Option<String> {
-        avatar_url(None, self.id, self.avatar.as_ref())
+        avatar_url(None, self.id, self.avatar.as_ref(), None, 1024)
+    }
+
+    /// Returns the formatted URL of the user's icon in the given format and size, if one exists.
+    ///
+    /// If no format is given, this behaves like [`Self::avatar_url`]. See [`ImageFormat`] for
+    /// how the size is rounded.
+    #[inline]
+    #[must_use]
+    pub fn avatar_url_sized(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        avatar_url(None, self.id, self.avatar.as_ref(), format, size)
     }
 
     /// Returns the formatted URL of the user's banner, if one exists.
@@ -391,7 +401,17 @@ impl User {
     #[inline]
     #[must_use]
     pub fn banner_url(&self) -> Option<String> {
-        banner_url(self.id, self.banner.as_ref())
+        banner_url(self.id, self.banner.as_ref(), None, 1024)
+    }
+
+    /// Returns the formatted URL of the user's banner in the given format and size, if one exists.
+    ///
+    /// If no format is given, this behaves like [`Self::banner_url`]. See [`ImageFormat`] for
+    /// how the size is rounded.
+    #[inline]
+    #[must_use]
+    pub fn banner_url_sized(&self, format: Option<ImageFormat>, size: u16) -> Option<String> {
+        banner_url(self.id, self.banner.as_ref(), format, size)
     }
 
     /// Creates a direct message channel between the [current user] and the user. This can also
@@ -532,7 +552,7 @@ impl User {
     #[inline]
     #[must_use]
     pub fn static_avatar_url(&self) -> Option<String> {
-        static_avatar_url(self.id, self.avatar.as_ref())
+        avatar_url(None, self.id, self.avatar.as_ref(), Some(ImageFormat::WebP), 1024)
     }
 
     /// Returns the "tag" for the user.
@@ -809,16 +829,13 @@ fn default_avatar_url(user: &User) -> String {
 }
 
 #[cfg(feature = "model")]
-fn static_avatar_url(user_id: UserId, hash: Option<&ImageHash>) -> Option<String> {
-    hash.map(|hash| cdn!("/avatars/{}/{}.webp?size=1024", user_id, hash))
-}
-
-#[cfg(feature = "model")]
-fn banner_url(user_id: UserId, hash: Option<&ImageHash>) -> Option<String> {
-    hash.map(|hash| {
-        let ext = if hash.is_animated() { "gif" } else { "webp" };
-        cdn!("/banners/{}/{}.{}?size=1024", user_id, hash, ext)
-    })
+fn banner_url(
+    user_id: UserId,
+    hash: Option<&ImageHash>,
+    format: Option<ImageFormat>,
+    size: u16,
+) -> Option<String> {
+    hash.map(|hash| cdn_asset_url(format_args!("banners/{user_id}"), hash, format, Some(size)))
 }
 
 #[cfg(feature = "model")]
@@ -869,7 +886,7 @@ mod test {
         use std::str::FromStr;
 
         use crate::model::id::UserId;
-        use crate::model::misc::ImageHash;
+        use crate::model::misc::{ImageFormat, ImageHash};
         use crate::model::user::User;
 
         #[test]
@@ -892,6 +909,12 @@ mod test {
             let expected = "/avatars/210/a_fb211703bcc04ee612c88d494df0272f.webp?size=1024";
             assert!(user.static_avatar_url().unwrap().ends_with(expected));
 
+            let expected = "/avatars/210/a_fb211703bcc04ee612c88d494df0272f.png?size=128";
+            let url = user.avatar_url_sized(Some(ImageFormat::Png), 100).unwrap();
+            assert!(url.ends_with(expected));
+            let expected = "/avatars/210/a_fb211703bcc04ee612c88d494df0272f.gif?size=4096";
+            assert!(user.avatar_url_sized(None, u16::MAX).unwrap().ends_with(expected));
+
             user.avatar = None;
             assert!(user.avatar_url().is_none());
 
@@ -919,5 +942,32 @@ mod test {
             user.discriminator = NonZeroU16::new(4);
             assert!(user.default_avatar_url().ends_with("4.png"));
         }
+
+        #[test]
+        fn face_falls_back_to_default_avatar() {
+            use crate::model::guild::Member;
+
+            let mut member = Member {
+                user: User {
+                    discriminator: None,
+                    id: UserId::new(737323631117598811),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+
+            // New username system
+            let expected = "https://cdn.discordapp.com/embed/avatars/5.png";
+            assert_eq!(member.user.face(), expected);
+            assert_eq!(member.user.static_face(), expected);
+            assert_eq!(member.face(), expected);
+
+            // Legacy username system
+            member.user.discriminator = NonZeroU16::new(1432);
+            let expected = "https://cdn.discordapp.com/embed/avatars/2.png";
+            assert_eq!(member.user.face(), expected);
+            assert_eq!(member.user.static_face(), expected);
+            assert_eq!(member.face(), expected);
+        }
     }
 }
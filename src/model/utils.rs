@@ -1,4 +1,6 @@
 use std::fmt;
+#[cfg(feature = "model")]
+use std::fmt::Write as _;
 use std::hash::Hash;
 use std::marker::PhantomData;
 use std::num::NonZeroU64;
@@ -19,32 +21,118 @@ pub fn is_false(v: &bool) -> bool {
     !v
 }
 
+/// Builds the CDN URL of an image asset stored under `path`.
+///
+/// If no format is given, GIF is used for animated images and WEBP otherwise. The size is rounded
+/// with [`cdn_size`].
+#[cfg(feature = "model")]
+pub(crate) fn cdn_asset_url(
+    path: fmt::Arguments<'_>,
+    hash: &ImageHash,
+    format: Option<ImageFormat>,
+    size: Option<u16>,
+) -> String {
+    cdn_url(path, hash, hash.is_animated(), format, size)
+}
+
+/// Rounds an image size up to the next power of two between 16 and 4096, as Discord rejects any
+/// other value.
+#[cfg(feature = "model")]
+fn cdn_size(size: u16) -> u16 {
+    size.clamp(16, 4096).next_power_of_two()
+}
+
+#[cfg(feature = "model")]
+fn cdn_url(
+    path: fmt::Arguments<'_>,
+    hash: &dyn fmt::Display,
+    animated: bool,
+    format: Option<ImageFormat>,
+    size: Option<u16>,
+) -> String {
+    let format = format.unwrap_or(if animated { ImageFormat::Gif } else { ImageFormat::WebP });
+
+    let mut url = cdn!("/{}/{}.{}", path, hash, format.extension());
+    if let Some(size) = size {
+        write!(url, "?size={}", cdn_size(size)).expect("writing to a String cannot fail");
+    }
+
+    url
+}
+
 #[cfg(feature = "model")]
 pub(super) fn avatar_url(
     guild_id: Option<GuildId>,
     user_id: UserId,
     hash: Option<&ImageHash>,
+    format: Option<ImageFormat>,
+    size: u16,
 ) -> Option<String> {
     hash.map(|hash| {
-        let ext = if hash.is_animated() { "gif" } else { "webp" };
-
         if let Some(guild_id) = guild_id {
-            cdn!("/guilds/{}/users/{}/avatars/{}.{}?size=1024", guild_id, user_id, hash, ext)
+            let path = format_args!("guilds/{guild_id}/users/{user_id}/avatars");
+            cdn_asset_url(path, hash, format, Some(size))
         } else {
-            cdn!("/avatars/{}/{}.{}?size=1024", user_id, hash, ext)
+            cdn_asset_url(format_args!("avatars/{user_id}"), hash, format, Some(size))
         }
     })
 }
 
 #[cfg(feature = "model")]
-pub(super) fn icon_url(id: GuildId, icon: Option<&ImageHash>) -> Option<String> {
-    icon.map(|icon| {
-        let ext = if icon.is_animated() { "gif" } else { "webp" };
+pub(super) fn icon_url(
+    id: GuildId,
+    icon: Option<&ImageHash>,
+    format: Option<ImageFormat>,
+    size: Option<u16>,
+) -> Option<String> {
+    icon.map(|icon| cdn_asset_url(format_args!("icons/{id}"), icon, format, size))
+}
+
+#[cfg(feature = "model")]
+pub(super) fn banner_url(
+    id: GuildId,
+    banner: Option<&str>,
+    format: Option<ImageFormat>,
+    size: u16,
+) -> Option<String> {
+    banner.map(|banner| {
+        let animated = banner.starts_with("a_");
+        cdn_url(format_args!("banners/{id}"), &banner, animated, format, Some(size))
+    })
+}
+
+#[cfg(feature = "model")]
+pub(super) fn splash_url(
+    id: GuildId,
+    splash: Option<&ImageHash>,
+    format: Option<ImageFormat>,
+    size: u16,
+) -> Option<String> {
+    splash.map(|splash| cdn_asset_url(format_args!("splashes/{id}"), splash, format, Some(size)))
+}
 
-        cdn!("/icons/{}/{}.{}", id, icon, ext)
+#[cfg(feature = "model")]
+pub(super) fn discovery_splash_url(
+    id: GuildId,
+    splash: Option<&ImageHash>,
+    format: Option<ImageFormat>,
+    size: u16,
+) -> Option<String> {
+    splash.map(|splash| {
+        cdn_asset_url(format_args!("discovery-splashes/{id}"), splash, format, Some(size))
     })
 }
 
+#[cfg(feature = "model")]
+pub(super) fn emoji_url(
+    id: EmojiId,
+    animated: bool,
+    format: Option<ImageFormat>,
+    size: Option<u16>,
+) -> String {
+    cdn_url(format_args!("emojis"), &id, animated, format, size)
+}
+
 pub fn deserialize_val<T, E>(val: Value) -> StdResult<T, E>
 where
     T: serde::de::DeserializeOwned,
@@ -450,3 +538,17 @@ where
         })
         .collect()
 }
+
+#[cfg(all(test, feature = "model"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cdn_size_is_a_power_of_two() {
+        for (size, expected) in
+            [(0, 16), (16, 16), (17, 32), (100, 128), (1024, 1024), (4095, 4096), (u16::MAX, 4096)]
+        {
+            assert_eq!(cdn_size(size), expected);
+        }
+    }
+}